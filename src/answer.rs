@@ -0,0 +1,103 @@
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    // Rows of a picture, such as the letters drawn on the CRT of day 10.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    // Compares against an answer written by hand or read from a file, so surrounding whitespace does not matter.
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Answer::Integer(value) => expected.trim().parse::<i64>().is_ok_and(|e| e == *value),
+            Answer::Text(text) => expected.trim() == text.trim(),
+            Answer::Grid(rows) => expected.trim_matches(['\r', '\n']).lines()
+                .map(str::trim_end)
+                .eq(rows.iter().map(|r| r.trim_end())),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Integer(value.into())
+            }
+        })*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, u8, u16, u32);
+
+// Larger unsigned answers are kept as text rather than truncated.
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                i64::try_from(value).map_or_else(|_| Answer::Text(value.to_string()), Answer::Integer)
+            }
+        })*
+    };
+}
+
+impl_from_unsigned!(u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::*;
+
+    #[test]
+    fn test_matches() {
+        assert!(Answer::Integer(42).matches("42\n"));
+        assert!(Answer::Integer(-7).matches(" -7 "));
+        assert!(!Answer::Integer(42).matches("420"));
+        assert!(!Answer::Integer(42).matches("forty-two"));
+        assert!(Answer::from("CMZ").matches("CMZ\n"));
+        assert!(!Answer::from("CMZ").matches("MCD"));
+    }
+
+    #[test]
+    fn test_grid_matches() {
+        let grid = Answer::Grid(vec!["#..".to_owned(), ".#.".to_owned()]);
+
+        assert!(grid.matches("#..\n.#."));
+        assert!(grid.matches("\n#..\n.#.\n"));
+        assert!(grid.matches("#..\r\n.#.\r\n"));
+        assert!(!grid.matches("#..\n..#"));
+        assert!(!grid.matches("#..\n.#.\n..#"));
+        assert_eq!("#..\n.#.", grid.to_string());
+    }
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::Integer(3), Answer::from(3usize));
+        assert_eq!(Answer::Integer(-3), Answer::from(-3i32));
+        assert_eq!(Answer::Text(u64::MAX.to_string()), Answer::from(u64::MAX));
+    }
+}
@@ -1,63 +1,483 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use std::str::FromStr;
 use std::time::{Duration, Instant};
 
-pub type PartSolver = fn(&str) -> String;
+use crate::answer::Answer;
+use crate::render::Render;
 
-pub type DoubleSolver = fn(&str) -> (String, String);
+// A puzzle whose input is parsed once and then shared by both parts.
+pub trait Solution {
+    // May borrow from the input, so errors found while solving can still point into it.
+    type Parsed<'a>;
 
-pub enum DaySolver {
-    Standard { part1: Option<PartSolver>, part2: Option<PartSolver> },
-    Double(DoubleSolver),
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError>;
+
+    // For puzzles whose examples use different constants than the real input, such as the row to scan.
+    fn parse_with<'a>(input: &'a str, _parameters: &Parameters) -> Result<Self::Parsed<'a>, SolveError> {
+        Self::parse(input)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Answer, SolveError>;
+
+    fn render(_parsed: &Self::Parsed<'_>) -> Result<Rendering, SolveError> {
+        Ok(None)
+    }
+}
+
+// Every day but 25 has a second part.
+pub trait SecondPart: Solution {
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Answer, SolveError>;
+}
+
+// The state a day ends in, for days with one worth looking at.
+pub type Rendering = Option<Box<dyn Render>>;
+
+pub struct DaySolver {
+    solve: fn(&str, &Parameters) -> Result<DayReport, SolveError>,
+    render: fn(&str, &Parameters) -> Result<Rendering, SolveError>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Parameters(pub HashMap<String, i64>);
+
+impl Parameters {
+    // Asks for pictures as drawn instead of the letters read from them.
+    pub const RAW_NAME: &'static str = "raw";
+
+    pub fn get(&self, name: &str) -> Option<i64> {
+        self.0.get(name).copied()
+    }
+}
+
+impl<N: Into<String>> FromIterator<(N, i64)> for Parameters {
+    fn from_iter<I: IntoIterator<Item = (N, i64)>>(parameters: I) -> Self {
+        Self(parameters.into_iter().map(|(name, value)| (name.into(), value)).collect())
+    }
+}
+
+pub struct Example {
+    pub input: &'static str,
+    pub answers: [Option<&'static str>; 2],
+    pub parameters: &'static [(&'static str, i64)],
+}
+
+impl Example {
+    pub const fn new(input: &'static str, part1: Option<&'static str>, part2: Option<&'static str>) -> Self {
+        Self { input, answers: [part1, part2], parameters: &[] }
+    }
+
+    pub const fn with_parameters(self, parameters: &'static [(&'static str, i64)]) -> Self {
+        Self { parameters, ..self }
+    }
+}
+
+pub struct Day {
+    pub solver: DaySolver,
+    pub examples: &'static [Example],
+}
+
+pub struct ExampleCheck {
+    pub part: usize,
+    pub expected: &'static str,
+    pub actual: Result<Answer, SolveError>,
+}
+
+impl ExampleCheck {
+    pub fn passed(&self) -> bool {
+        self.actual.as_ref().is_ok_and(|a| a.matches(self.expected))
+    }
+}
+
+impl Day {
+    // Only the parts with a known answer are checked.
+    pub fn check_example(&self, example: &Example) -> Result<Vec<ExampleCheck>, SolveError> {
+        let report = self.solver.solve_with(example.input, &example.parameters.iter().copied().collect())?;
+        Ok([report.part1, report.part2].into_iter()
+            .zip(example.answers)
+            .enumerate()
+            .filter_map(|(i, (report, expected))| Some(ExampleCheck {
+                part: i + 1,
+                expected: expected?,
+                actual: report.map_or_else(|| Ok(Answer::from("")), |r| r.result),
+            }))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+pub fn assert_examples(day: &Day) {
+    assert!(!day.examples.is_empty(), "no examples");
+    for (i, example) in day.examples.iter().enumerate() {
+        for check in day.check_example(example).unwrap() {
+            assert!(check.passed(), "example {} part {}: expected {}, got {:?}", i + 1, check.part, check.expected, check.actual);
+        }
+    }
 }
 
+#[derive(Debug)]
 pub struct PartReport {
-    pub result: String,
+    // A part that fails leaves the other part of the day standing.
+    pub result: Result<Answer, SolveError>,
     pub timing: Option<Duration>,
+    pub statistics: Option<Statistics>,
 }
 
+#[derive(Debug)]
 pub struct DayReport {
+    pub parse_timing: Option<Duration>,
+    pub parse_statistics: Option<Statistics>,
     pub part1: Option<PartReport>,
     pub part2: Option<PartReport>,
+    // False for days like 25, whose missing second part is expected rather than unimplemented.
+    pub has_part2: bool,
     pub total_timing: Option<Duration>,
+    pub statistics: Option<Statistics>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Statistics {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+    pub p95: Duration,
+}
+
+impl Statistics {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let runs = sorted.len();
+
+        let median = if runs.is_multiple_of(2) {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        } else {
+            sorted[runs / 2]
+        };
+
+        let mean = sorted.iter().sum::<Duration>() / runs as u32;
+        let variance = sorted.iter()
+            .map(|d| (d.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>() / runs as f64;
+
+        // Nearest-rank percentile.
+        let p95 = sorted[(runs * 95).div_ceil(100) - 1];
+
+        Some(Self { runs, min: sorted[0], median, mean, std_dev: Duration::from_secs_f64(variance.sqrt()), p95 })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SolveError {
+    pub message: String,
+    pub location: Option<Location>,
+    // Address range of the offending part of the input, which is turned into a location once the input is known.
+    fragment: Option<Range<usize>>,
+}
+
+impl SolveError {
+    pub fn new<M: Into<String>>(message: M) -> Self {
+        Self { message: message.into(), location: None, fragment: None }
+    }
+
+    // The fragment must be a slice of the input given to the solver for its location to be resolved.
+    pub fn at<M: Into<String>>(fragment: &str, message: M) -> Self {
+        let address = fragment.as_ptr() as usize;
+        Self { message: message.into(), location: None, fragment: Some(address..address + fragment.len()) }
+    }
+
+    fn locate(mut self, input: &str) -> Self {
+        let Some(fragment) = self.fragment.take() else {
+            return self;
+        };
+
+        // Fragments of any other string are left without a location.
+        let start = input.as_ptr() as usize;
+        if fragment.start < start || fragment.end > start + input.len() || !input.is_char_boundary(fragment.start - start) {
+            return self;
+        }
+
+        let offset = fragment.start - start;
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        self.location = Some(Location {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: input[line_start..].lines().next().unwrap_or_default().to_owned(),
+        });
+        self
+    }
 }
 
-fn solve_part(solver: &Option<PartSolver>, input: &str) -> Option<PartReport> {
-    let Some(solver) = solver else {
-        return None;
-    };
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(Location { line, column, text }) => write!(f, "{} at line {}, column {}: {}", self.message, line, column, text),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
 
+impl std::error::Error for SolveError {}
+
+pub fn parse<T: FromStr>(s: &str) -> Result<T, SolveError> {
+    s.parse().map_err(|_| SolveError::at(s, format!("Invalid value {:?}", s)))
+}
+
+pub fn split_once<'a>(s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), SolveError> {
+    s.split_once(delimiter).ok_or_else(|| SolveError::at(s, format!("Expected {:?}", delimiter)))
+}
+
+pub fn strip_prefix<'a>(s: &'a str, prefix: &str) -> Result<&'a str, SolveError> {
+    s.strip_prefix(prefix).ok_or_else(|| SolveError::at(s, format!("Expected {:?}", prefix)))
+}
+
+fn solve_part<F: FnOnce() -> Result<Answer, SolveError>>(input: &str, solver: F) -> PartReport {
     let start = Instant::now();
-    let result = solver(input);
+    let result = solver();
     let time = start.elapsed();
-    Some(PartReport {
-        result,
+    PartReport {
+        result: result.map_err(|e| e.locate(input)),
         timing: Some(time),
+        statistics: None,
+    }
+}
+
+type PartSolver<S> = for<'a, 'b> fn(&'b <S as Solution>::Parsed<'a>) -> Result<Answer, SolveError>;
+
+fn solve_solution<S: SecondPart>(input: &str, parameters: &Parameters) -> Result<DayReport, SolveError> {
+    solve_parts::<S>(input, parameters, Some(S::part2))
+}
+
+fn solve_first_part<S: Solution>(input: &str, parameters: &Parameters) -> Result<DayReport, SolveError> {
+    solve_parts::<S>(input, parameters, None)
+}
+
+fn solve_parts<S: Solution>(input: &str, parameters: &Parameters, part2: Option<PartSolver<S>>) -> Result<DayReport, SolveError> {
+    let start = Instant::now();
+    let parsed = S::parse_with(input, parameters)?;
+    let parse_timing = start.elapsed();
+
+    let part1 = solve_part(input, || S::part1(&parsed));
+    let has_part2 = part2.is_some();
+    let part2 = part2.map(|part2| solve_part(input, || part2(&parsed)));
+    let total_timing = [Some(&part1), part2.as_ref()].into_iter()
+        .flatten()
+        .filter_map(|p| p.timing)
+        .sum::<Duration>() + parse_timing;
+
+    Ok(DayReport {
+        parse_timing: Some(parse_timing),
+        parse_statistics: None,
+        part1: Some(part1),
+        part2,
+        has_part2,
+        total_timing: Some(total_timing),
+        statistics: None,
     })
 }
 
+fn render_solution<S: Solution>(input: &str, parameters: &Parameters) -> Result<Rendering, SolveError> {
+    S::render(&S::parse_with(input, parameters)?)
+}
+
 impl DaySolver {
-    pub fn solve(&self, input: &str) -> DayReport {
-        match self {
-            DaySolver::Standard { part1, part2 } => {
-                let part1 = solve_part(part1, input);
-                let part2 = solve_part(part2, input);
-                let total_timing = match (&part1, &part2) {
-                    | (Some(PartReport { timing: Some(t1), .. }), Some(PartReport { timing: Some(t2), .. })) => Some(*t1 + *t2),
-                    _ => None,
-                };
-
-                DayReport { part1, part2, total_timing }
-            }
-            DaySolver::Double(solver) => {
-                let start = Instant::now();
-                let (s1, s2) = solver(input);
-                let total_timing = start.elapsed();
-
-                DayReport {
-                    part1: Some(PartReport { result: s1, timing: None }),
-                    part2: Some(PartReport { result: s2, timing: None }),
-                    total_timing: Some(total_timing),
-                }
+    pub const fn new<S: SecondPart>() -> Self {
+        Self { solve: solve_solution::<S>, render: render_solution::<S> }
+    }
+
+    pub const fn without_part2<S: Solution>() -> Self {
+        Self { solve: solve_first_part::<S>, render: render_solution::<S> }
+    }
+
+    // None for days without anything to render.
+    pub fn render(&self, input: &str, parameters: &Parameters) -> Result<Rendering, SolveError> {
+        (self.render)(input, parameters).map_err(|e| e.locate(input))
+    }
+
+    pub fn solve(&self, input: &str) -> Result<DayReport, SolveError> {
+        self.solve_with(input, &Parameters::default())
+    }
+
+    pub fn solve_with(&self, input: &str, parameters: &Parameters) -> Result<DayReport, SolveError> {
+        (self.solve)(input, parameters).map_err(|e| e.locate(input))
+    }
+
+    // Solves the day repeatedly after discarding the warm-up runs, reporting the answers of the last run.
+    pub fn bench(&self, input: &str, parameters: &Parameters, warmup: usize, runs: usize) -> Result<DayReport, SolveError> {
+        for _ in 0..warmup {
+            self.solve_with(input, parameters)?;
+        }
+
+        let mut parse_timings = vec![];
+        let mut part1_timings = vec![];
+        let mut part2_timings = vec![];
+        let mut total_timings = vec![];
+        let mut report = self.solve_with(input, parameters)?;
+        for run in 0..runs {
+            if run > 0 {
+                report = self.solve_with(input, parameters)?;
             }
+
+            parse_timings.extend(report.parse_timing);
+            part1_timings.extend(report.part1.as_ref().and_then(|p| p.timing));
+            part2_timings.extend(report.part2.as_ref().and_then(|p| p.timing));
+            total_timings.extend(report.total_timing);
+        }
+
+        report.parse_statistics = Statistics::from_samples(&parse_timings);
+        if let Some(part1) = &mut report.part1 {
+            part1.statistics = Statistics::from_samples(&part1_timings);
+        }
+        if let Some(part2) = &mut report.part2 {
+            part2.statistics = Statistics::from_samples(&part2_timings);
+        }
+        report.statistics = Statistics::from_samples(&total_timings);
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day::*;
+
+    struct Failing;
+
+    impl Solution for Failing {
+        type Parsed<'a> = ();
+
+        fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+            let line = input.lines().nth(1).unwrap();
+            parse::<i32>(&line[2..])?;
+            Ok(())
+        }
+
+        fn part1(_: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+            Err(SolveError::new("No solution"))
         }
     }
-}
\ No newline at end of file
+
+    impl SecondPart for Failing {
+        fn part2(_: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+            Err(SolveError::new("No solution"))
+        }
+    }
+
+    struct Length;
+
+    impl Solution for Length {
+        type Parsed<'a> = usize;
+
+        fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+            Ok(input.len())
+        }
+
+        fn parse_with<'a>(input: &'a str, parameters: &Parameters) -> Result<Self::Parsed<'a>, SolveError> {
+            Ok(parameters.get("length").map_or(input.len(), |l| l as usize))
+        }
+
+        fn part1(length: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+            Ok((*length).into())
+        }
+    }
+
+    #[test]
+    fn test_error_location() {
+        let Err(error) = DaySolver::new::<Failing>().solve("12\nab34x\n") else {
+            panic!("expected error");
+        };
+
+        assert_eq!(Some(Location { line: 2, column: 3, text: "ab34x".to_owned() }), error.location);
+        assert_eq!("Invalid value \"34x\" at line 2, column 3: ab34x", error.to_string());
+    }
+
+    #[test]
+    fn test_error_outside_input() {
+        let buffer = "12\nab34x";
+        let copy = buffer.to_owned();
+        assert_eq!(None, SolveError::at(&copy[5..], "Invalid").locate(buffer).location);
+        assert_eq!(None, SolveError::at(&buffer[3..], "Invalid").locate(&buffer[..5]).location);
+        assert!(SolveError::at(&buffer[3..5], "Invalid").locate(&buffer[..5]).location.is_some());
+    }
+
+    #[test]
+    fn test_error_without_location() {
+        let report = DaySolver::new::<Failing>().solve("12\n1234").unwrap();
+        let Err(error) = report.part1.unwrap().result else {
+            panic!("expected error");
+        };
+
+        assert_eq!(None, error.location);
+        assert_eq!("No solution", error.to_string());
+        assert!(report.part2.is_some());
+    }
+
+    #[test]
+    fn test_statistics() {
+        let samples = [5, 1, 4, 2, 3, 100].map(Duration::from_millis);
+        let statistics = Statistics::from_samples(&samples).unwrap();
+
+        assert_eq!(6, statistics.runs);
+        assert_eq!(Duration::from_millis(1), statistics.min);
+        assert_eq!(Duration::from_micros(3500), statistics.median);
+        assert_eq!(Duration::from_nanos(19166666), statistics.mean);
+        assert_eq!(36172, statistics.std_dev.as_micros());
+        assert_eq!(Duration::from_millis(100), statistics.p95);
+    }
+
+    #[test]
+    fn test_statistics_without_samples() {
+        assert_eq!(None, Statistics::from_samples(&[]));
+    }
+
+    #[test]
+    fn test_parameters() {
+        let solver = DaySolver::without_part2::<Length>();
+
+        assert_eq!(Answer::Integer(3), solver.solve("abc").unwrap().part1.unwrap().result.unwrap());
+        assert_eq!(Answer::Integer(10), solver.solve_with("abc", &Parameters::from_iter([("length", 10)])).unwrap().part1.unwrap().result.unwrap());
+    }
+
+    #[test]
+    fn test_timings() {
+        let report = DaySolver::without_part2::<Length>().solve("abc").unwrap();
+        let part1 = report.part1.unwrap();
+
+        assert!(report.part2.is_none());
+        assert_eq!(report.total_timing, Some(report.parse_timing.unwrap() + part1.timing.unwrap()));
+    }
+
+    #[test]
+    fn test_check_example() {
+        let day = Day { solver: DaySolver::without_part2::<Length>(), examples: &[] };
+        let checks = day.check_example(&Example::new("abc", Some("3"), None)).unwrap();
+
+        assert_eq!(1, checks.len());
+        assert!(checks[0].passed());
+        assert!(!day.check_example(&Example::new("ab", Some("3"), None)).unwrap()[0].passed());
+    }
+
+    #[test]
+    fn test_bench_runs() {
+        let report = DaySolver::without_part2::<Length>().bench("abc", &Parameters::default(), 2, 5).unwrap();
+
+        assert_eq!(Answer::Integer(3), report.part1.as_ref().unwrap().result.clone().unwrap());
+        assert_eq!(5, report.part1.unwrap().statistics.unwrap().runs);
+        assert_eq!(5, report.parse_statistics.unwrap().runs);
+        assert_eq!(5, report.statistics.unwrap().runs);
+    }
+}
@@ -0,0 +1,163 @@
+use std::{env, fs};
+use std::path::Path;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use ureq::{Agent, AgentBuilder};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// Inputs are only requested when missing, but runs of every day should still not hammer the server.
+pub const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(3);
+
+const SESSION_VARIABLE: &str = "AOC_SESSION";
+const SESSION_FILE: &str = ".aoc-session";
+
+const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),
+    " (github.com/NVedsted/Advent-Of-Code-2022 by ", env!("CARGO_PKG_AUTHORS"), ")",
+);
+
+// The session token is taken from the environment, falling back to a file in the working directory.
+pub fn find_session() -> Result<String, String> {
+    if let Ok(session) = env::var(SESSION_VARIABLE) {
+        return Ok(session.trim().to_owned());
+    }
+
+    fs::read_to_string(SESSION_FILE)
+        .map(|s| s.trim().to_owned())
+        .map_err(|_| format!("No session token; set {} or write it to {}", SESSION_VARIABLE, SESSION_FILE))
+}
+
+pub struct Fetcher {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: String, min_interval: Duration) -> Self {
+        Self {
+            agent: AgentBuilder::new().user_agent(USER_AGENT).timeout(Duration::from_secs(30)).build(),
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session,
+            min_interval,
+            last_request: Mutex::new(None),
+        }
+    }
+
+    // Reads the cached input at `path`, downloading it there first if it is missing.
+    pub fn input(&self, year: u32, day: usize, path: &Path) -> Result<String, String> {
+        if let Ok(input) = fs::read_to_string(path) {
+            return Ok(input);
+        }
+
+        let input = self.download(year, day)?;
+        path.parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(path, &input))
+            .map_err(|e| format!("Failed to cache input at {}: {}", path.display(), e))?;
+        Ok(input)
+    }
+
+    fn download(&self, year: u32, day: usize) -> Result<String, String> {
+        // Held for the whole request so concurrent days queue up behind each other.
+        let mut last_request = self.last_request.lock().unwrap();
+        if let Some(elapsed) = last_request.map(|t| t.elapsed()) {
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        *last_request = Some(Instant::now());
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let response = self.agent.get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        match response {
+            Ok(response) => response.into_string().map_err(|e| format!("Failed to read input: {}", e)),
+            Err(ureq::Error::Status(status, _)) => Err(format!("Server responded with status {} for {}", status, url)),
+            Err(error) => Err(format!("Failed to fetch {}: {}", url, error)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+
+    use crate::fetch::*;
+
+    // Serves the given responses in order, returning the received request heads once done.
+    fn serve(responses: &[&'static str]) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let responses = responses.to_vec();
+        let server = thread::spawn(move || {
+            responses.into_iter()
+                .map(|response| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let mut request = String::new();
+                    let mut reader = BufReader::new(&mut stream);
+                    while reader.read_line(&mut request).unwrap() > 2 {}
+                    stream.write_all(response.as_bytes()).unwrap();
+                    request
+                })
+                .collect()
+        });
+        (base_url, server)
+    }
+
+    fn cache_path(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("aoc-fetch-{}-{}", std::process::id(), name)).join("day1.in");
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn test_fetch_and_cache() {
+        let (base_url, server) = serve(&["HTTP/1.1 200 OK\r\nConnection: close\r\nContent-Length: 4\r\n\r\n1\n2\n"]);
+        let fetcher = Fetcher::new(&base_url, "abc".to_owned(), Duration::ZERO);
+        let path = cache_path("cache");
+
+        assert_eq!("1\n2\n", fetcher.input(2022, 1, &path).unwrap());
+        assert_eq!("1\n2\n", fs::read_to_string(&path).unwrap());
+        // Served from the cache, as the server only answers once.
+        assert_eq!("1\n2\n", fetcher.input(2022, 1, &path).unwrap());
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=abc\r\n"));
+        assert!(requests[0].contains(&format!("User-Agent: {}\r\n", USER_AGENT)));
+    }
+
+    #[test]
+    fn test_error_status() {
+        let (base_url, server) = serve(&["HTTP/1.1 404 Not Found\r\nConnection: close\r\nContent-Length: 0\r\n\r\n"]);
+        let fetcher = Fetcher::new(&base_url, "abc".to_owned(), Duration::ZERO);
+        let path = cache_path("error");
+
+        let error = fetcher.input(2022, 1, &path).unwrap_err();
+        assert!(error.starts_with("Server responded with status 404"), "{}", error);
+        assert!(!path.exists());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let response = "HTTP/1.1 200 OK\r\nConnection: close\r\nContent-Length: 1\r\n\r\n1";
+        let (base_url, server) = serve(&[response, response]);
+        let fetcher = Fetcher::new(&base_url, "abc".to_owned(), Duration::from_millis(200));
+
+        let start = Instant::now();
+        fetcher.input(2022, 1, &cache_path("rate1")).unwrap();
+        fetcher.input(2022, 1, &cache_path("rate2")).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        server.join().unwrap();
+    }
+}
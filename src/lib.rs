@@ -0,0 +1,75 @@
+use std::fmt::{Display, Formatter};
+
+use crate::answer::Answer;
+use crate::day::{DayReport, Parameters, SolveError};
+
+pub mod answer;
+pub mod day;
+pub mod fetch;
+pub mod ocr;
+pub mod render;
+pub mod years;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Answers {
+    pub part1: Answer,
+    // Missing for days without a second part.
+    pub part2: Option<Answer>,
+}
+
+// Fails with the error of the first part that failed.
+impl TryFrom<DayReport> for Answers {
+    type Error = SolveError;
+
+    fn try_from(report: DayReport) -> Result<Self, Self::Error> {
+        Ok(Self {
+            part1: report.part1.map_or_else(|| Ok(Answer::from("")), |p| p.result)?,
+            part2: report.part2.map(|p| p.result).transpose()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    NoSolver { year: u32, day: usize },
+    Solve(SolveError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NoSolver { year, day } => write!(f, "No solver for day {} of {}", day, year),
+            Error::Solve(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::NoSolver { .. } => None,
+            Error::Solve(error) => Some(error),
+        }
+    }
+}
+
+impl From<SolveError> for Error {
+    fn from(error: SolveError) -> Self {
+        Error::Solve(error)
+    }
+}
+
+// Solves a day of the default year.
+pub fn solve(day: usize, input: &str) -> Result<Answers, Error> {
+    solve_year(years::DEFAULT_YEAR, day, input)
+}
+
+pub fn solve_year(year: u32, day: usize, input: &str) -> Result<Answers, Error> {
+    solve_with(year, day, input, &Parameters::default())
+}
+
+// Solves a day with parameters such as the row day 15 looks at.
+pub fn solve_with(year: u32, day: usize, input: &str, parameters: &Parameters) -> Result<Answers, Error> {
+    let day_solver = years::get(year, day).ok_or(Error::NoSolver { year, day })?;
+    Ok(day_solver.solver.solve_with(input, parameters)?.try_into()?)
+}
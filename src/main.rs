@@ -1,26 +1,45 @@
-use std::{fs, io};
+use std::{fs, io, panic, thread};
+use std::any::Any;
+use std::collections::BTreeMap;
 use std::io::Read;
-use std::path::Path;
-use std::time::Duration;
+use std::panic::AssertUnwindSafe;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, OnceLock};
+use std::time::Instant;
 
-use clap::Parser;
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand};
 use colored::Colorize;
 
-use crate::day::{DayReport, PartReport};
+use aoc2022_rs::answer::Answer;
+use aoc2022_rs::day::{Day, DayReport, Parameters, PartReport};
+use aoc2022_rs::fetch::{self, Fetcher};
+use aoc2022_rs::render::Backend;
+use aoc2022_rs::years;
 
-mod day;
-mod days;
+use crate::report::{present_answer, DayRun, Format, Recording, RunError, Validation};
+
+mod report;
+mod tui;
+mod watch;
 
 #[derive(Parser)]
 #[command(author, about)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     #[arg(help = "Specifies the day to solve. Otherwise, all days are run. (1-25)")]
     day: Option<usize>,
 
+    #[arg(help = "The event year to solve.", long, default_value_t = years::DEFAULT_YEAR)]
+    year: u32,
+
     #[arg(help = "Read from standard input.", long)]
     stdin: bool,
 
-    #[arg(help = "The file to read input from. If not provided, input/day<#>.in will be used.", long, short)]
+    #[arg(help = "The file to read input from. If not provided, input/<year>/day<#>.in will be used.", long, short)]
     file: Option<String>,
 
     #[arg(help = "Attempts to validate outputs.", long, short)]
@@ -28,113 +47,359 @@ struct Args {
 
     #[arg(help = "Show timings.", long, short)]
     timings: bool,
+
+    #[arg(help = "Benchmarks each day by solving it N times and reporting timing statistics.", long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    bench: Option<u32>,
+
+    #[arg(help = "Number of discarded runs before benchmarking.", long, value_name = "N", default_value_t = 1)]
+    warmup: u32,
+
+    #[arg(help = "The format to report results in.", long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    #[arg(help = "Records the answers as the expected outputs used for validation.", long)]
+    record: bool,
+
+    #[arg(help = "Overwrites recorded answers that differ from the new ones.", long, requires = "record")]
+    force: bool,
+
+    #[arg(help = "Solves the examples from the puzzle descriptions instead of the input and checks their answers.", long, conflicts_with_all = ["stdin", "file", "validate", "bench", "record"])]
+    example: bool,
+
+    #[arg(help = "Number of days to solve concurrently.", long, short, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: u32,
+
+    #[arg(help = "Shows answers drawn as pictures as-is instead of reading the letters in them.", long)]
+    raw: bool,
+
+    #[arg(help = "Sets a parameter of the solvers, such as the row day 15 looks at.", long = "param", value_name = "NAME=VALUE", value_parser = parse_parameter, conflicts_with = "example")]
+    params: Vec<(String, i64)>,
+
+    #[arg(help = "Downloads missing inputs using the session token from AOC_SESSION or .aoc-session.", long, conflicts_with_all = ["stdin", "file", "example"])]
+    fetch: bool,
+
+    #[arg(help = "The server to download inputs from.", long, value_name = "URL", default_value = fetch::DEFAULT_BASE_URL, requires = "fetch")]
+    base_url: String,
+
+    #[arg(help = "Solves again whenever the input or expected output files of a day change.", long, conflicts_with_all = ["stdin", "example", "record"])]
+    watch: bool,
+
+    #[arg(help = "Draws the final state of the day to a .txt, .ppm or .svg file.", long, value_name = "PATH", requires = "day", conflicts_with_all = ["example", "watch"])]
+    render: Option<PathBuf>,
 }
 
-fn main() {
-    let args = Args::parse();
+#[derive(Subcommand)]
+enum Command {
+    #[command(about = "Browses the days, their answers and timings in an interactive terminal interface.")]
+    Tui,
+}
 
-    if let Some(day) = args.day {
-        if run_day(day, &args).is_none() {
-            println!("{}", format!("No solver for day {}", day).bright_yellow());
-        }
-    } else {
-        let days = (1..=25)
-            .map(|i| run_day(i, &args))
-            .collect::<Vec<_>>();
+impl Args {
+    fn show_timings(&self) -> bool {
+        self.timings || self.bench.is_some()
+    }
+
+    fn parameters(&self) -> Parameters {
+        let raw = self.raw.then(|| (Parameters::RAW_NAME.to_owned(), 1));
+        self.params.iter().cloned().chain(raw).collect()
+    }
+}
 
-        if args.timings {
-            let total = days
-                .into_iter()
-                .filter_map(|r| r.and_then(|r| r.total_timing))
-                .sum::<Duration>();
+fn parse_parameter(parameter: &str) -> Result<(String, i64), String> {
+    let (name, value) = parameter.split_once('=').ok_or("expected NAME=VALUE")?;
+    let value = value.parse().map_err(|_| format!("invalid value {:?}", value))?;
+    Ok((name.to_owned(), value))
+}
 
-            println!("\nTotal time: {:?}", total);
+fn main() {
+    let mut args = Args::parse();
+
+    // Clap has no way to declare arguments that conflict with a subcommand.
+    if let Some(Command::Tui) = args.command {
+        let conflicts = [("--stdin", args.stdin), ("--example", args.example), ("--watch", args.watch)];
+        if let Some((flag, _)) = conflicts.into_iter().find(|&(_, set)| set) {
+            Args::command().error(ErrorKind::ArgumentConflict, format!("the argument '{}' cannot be used with the 'tui' subcommand", flag)).exit();
         }
     }
-}
 
-fn run_day(day: usize, args: &Args) -> Option<DayReport> {
-    if day < 1 || day > 25 {
-        println!("{}", "Day out of bounds".red());
-        return None;
+    if !years::has_year(args.year) {
+        println!("{}", format!("No solvers for {}", args.year).red());
+        return;
     }
 
-    let Some(solver) = &days::DAYS[day - 1] else {
-        return None;
+    let days = match args.day {
+        Some(day) if !(1..=25).contains(&day) => {
+            println!("{}", "Day out of bounds".red());
+            return;
+        }
+        Some(day) => vec![day],
+        None => (1..=25).collect(),
     };
 
-    let input = if args.stdin {
-        let mut input = String::new();
-        io::stdin().read_to_string(&mut input).unwrap();
+    if args.example {
+        days.into_iter().for_each(|day| run_examples(args.year, day));
+        return;
+    }
 
-        input
+    let fetcher = if args.fetch {
+        match fetch::find_session() {
+            Ok(session) => Some(Fetcher::new(&args.base_url, session, fetch::MIN_REQUEST_INTERVAL)),
+            Err(error) => {
+                println!("{}", error.red());
+                return;
+            }
+        }
     } else {
-        let filename = match &args.file {
-            Some(f) => f.to_owned(),
-            None => format!("input/day{}.in", day),
-        };
-        let path = Path::new(&filename);
+        None
+    };
+
+    if let Some(Command::Tui) = args.command {
+        // The interface shows validation results for every run.
+        args.validate = true;
+        if let Err(error) = tui::run(&args, fetcher.as_ref()) {
+            println!("{}", format!("Terminal error: {}", error).red());
+        }
+        return;
+    }
 
-        if !path.exists() {
-            println!("{}", format!("Day {} has no input", day).red());
-            return None;
+    let renderer = args.format.renderer(args.year, args.validate, args.show_timings());
+    if args.watch {
+        if let Err(error) = watch::watch(&days, &args, fetcher.as_ref(), renderer.as_ref()) {
+            println!("{}", error.red());
         }
+        return;
+    }
 
-        fs::read_to_string(filename).unwrap()
-    }.replace('\r', "");
+    print!("{}", renderer.render_header());
 
-    let report = solver.solve(&input);
+    let start = Instant::now();
+    let mut runs = vec![];
+    run_days(&days, &args, fetcher.as_ref(), |run| {
+        print!("{}", renderer.render_day(&run));
+        runs.push(run);
+    });
+    let wall_time = start.elapsed();
 
-    print!("Day {}", day);
+    print!("{}", renderer.render_summary(&runs, wall_time));
 
-    if let Some(total_timing) = report.total_timing {
-        if args.timings {
-            print!(" ({:?})", total_timing);
+    if let (Some(path), Some(day)) = (&args.render, args.day) {
+        // Reported on standard error so that JSON and CSV reports stay parseable.
+        match render_day(day, &args, fetcher.as_ref(), path) {
+            Ok(()) => eprintln!("{}", format!("Rendered day {} to {}", day, path.display()).green()),
+            Err(error) => eprintln!("{}", error.red()),
         }
     }
+}
 
-    println!();
+// Solves the days on worker threads, passing each run on in day order as soon as all earlier days are done.
+fn run_days<F: FnMut(DayRun)>(days: &[usize], args: &Args, fetcher: Option<&Fetcher>, mut on_run: F) {
+    let next_day = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
 
-    present_part(day, 1, args.validate, args.timings, &report.part1);
-    present_part(day, 2, args.validate, args.timings, &report.part2);
+    thread::scope(|scope| {
+        for _ in 0..(args.jobs as usize).min(days.len()) {
+            let sender = sender.clone();
+            let next_day = &next_day;
+            scope.spawn(move || {
+                while let Some(&day) = days.get(next_day.fetch_add(1, Ordering::Relaxed)) {
+                    if sender.send((day, run_day(day, args, fetcher))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
 
-    Some(report)
+        let mut pending = BTreeMap::new();
+        let mut remaining = days.iter();
+        let mut awaited = remaining.next();
+        for (day, run) in receiver {
+            pending.insert(day, run);
+            while let Some(run) = awaited.and_then(|day| pending.remove(day)) {
+                on_run(run);
+                awaited = remaining.next();
+            }
+        }
+    });
 }
 
-fn present_part(day: usize, part: usize, validate: bool, timings: bool, report: &Option<PartReport>) {
-    print!("\tPart {}: ", part);
-    if let Some(report) = report {
-        print!("{}", report.result.blue());
+fn run_day(day: usize, args: &Args, fetcher: Option<&Fetcher>) -> DayRun {
+    let outcome = solve_day(day, args, fetcher);
 
-        if let Some(timing) = report.timing {
-            if timings {
-                print!(" ({:?})", timing);
-            }
+    let validation = match (&outcome, args.validate) {
+        (Ok(report), true) => [(1, &report.part1), (2, &report.part2)]
+            .map(|(part, report)| answer(report).map(|a| validate_part(args.year, day, part, a))),
+        _ => [None, None],
+    };
+
+    // Recorded after validating, so validation still compares against the previous answers.
+    let recording = match (&outcome, args.record) {
+        (Ok(report), true) => [(1, &report.part1), (2, &report.part2)]
+            .map(|(part, report)| answer(report).map(|a| record_part(args.year, day, part, a, args.force))),
+        _ => [None, None],
+    };
+
+    DayRun { day, outcome, validation, recording }
+}
+
+// Parts that failed have nothing to validate or record.
+fn answer(report: &Option<PartReport>) -> Option<&Answer> {
+    report.as_ref().and_then(|r| r.result.as_ref().ok())
+}
+
+fn solve_day(day: usize, args: &Args, fetcher: Option<&Fetcher>) -> Result<DayReport, RunError> {
+    let Some(Day { solver, .. }) = years::get(args.year, day) else {
+        return Err(RunError::NoSolver);
+    };
+
+    let input = read_input(day, args, fetcher)?;
+    // A panicking solver only fails its own day rather than the whole run.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| match args.bench {
+        Some(runs) => solver.bench(&input, &args.parameters(), args.warmup as usize, runs as usize),
+        None => solver.solve_with(&input, &args.parameters()),
+    }));
+
+    result.map_err(|payload| RunError::Panic(panic_message(payload.as_ref())))?
+        .map_err(RunError::Solve)
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Unknown panic".to_owned()
+    }
+}
+
+fn read_input(day: usize, args: &Args, fetcher: Option<&Fetcher>) -> Result<String, RunError> {
+    let input = if args.stdin {
+        read_stdin().map_err(|e| RunError::Read(e.clone()))?.to_owned()
+    } else {
+        let path = input_path(day, args);
+        match fetcher {
+            Some(fetcher) => fetcher.input(args.year, day, &path).map_err(RunError::Fetch)?,
+            None if !path.exists() => return Err(RunError::NoInput),
+            None => fs::read_to_string(&path).map_err(|e| RunError::Read(format!("{}: {}", path.display(), e)))?,
         }
+    };
+    Ok(input.replace('\r', ""))
+}
+
+fn render_day(day: usize, args: &Args, fetcher: Option<&Fetcher>, path: &Path) -> Result<(), String> {
+    let backend = Backend::from_path(path).ok_or("Unknown render format; use a .txt, .ppm or .svg file")?;
+    let Some(Day { solver, .. }) = years::get(args.year, day) else {
+        return Err(RunError::NoSolver.describe(day));
+    };
+
+    let input = read_input(day, args, fetcher).map_err(|e| e.describe(day))?;
+    let drawing = solver.render(&input, &args.parameters())
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Day {} has nothing to render", day))?;
+    fs::write(path, backend.render(drawing.as_ref())).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+fn run_examples(year: u32, day: usize) {
+    let Some(solver) = years::get(year, day) else {
+        println!("{}", format!("No solver for day {}", day).bright_yellow());
+        return;
+    };
+
+    println!("Day {}", day);
+    if solver.examples.is_empty() {
+        println!("\t{}", "No examples".bright_yellow());
+    }
+
+    for (i, example) in solver.examples.iter().enumerate() {
+        let checks = match solver.check_example(example) {
+            Ok(checks) => checks,
+            Err(error) => {
+                println!("\tExample {}: {}", i + 1, format!("Error: {}", error).red());
+                continue;
+            }
+        };
 
-        println!();
+        for check in checks {
+            let actual = match &check.actual {
+                Ok(actual) => actual,
+                Err(error) => {
+                    println!("\tExample {} part {}: {}", i + 1, check.part, format!("Error: {}", error).red());
+                    continue;
+                }
+            };
 
-        if validate {
-            validate_part(day, part, &report.result);
+            print!("\tExample {} part {}: {} ", i + 1, check.part, present_answer(actual).blue());
+            if check.passed() {
+                println!("{}", "Passed".green());
+            } else {
+                println!("{}", format!("Failed! Expected: {}", check.expected).red());
+            }
         }
-    } else {
-        println!("{}", "Not implemented".bright_yellow());
     }
 }
 
-fn validate_part(day: usize, part: usize, actual: &str) {
-    let filename = format!("output/part{}/day{}.out", part, day);
-    let path = Path::new(&filename);
+fn input_path(day: usize, args: &Args) -> PathBuf {
+    match &args.file {
+        Some(f) => PathBuf::from(f),
+        None => data_path("input", args.year, &format!("day{}.in", day)),
+    }
+}
+
+// Standard input is read once and shared by all days.
+fn read_stdin() -> Result<&'static str, &'static String> {
+    static STDIN: OnceLock<Result<String, String>> = OnceLock::new();
+    STDIN.get_or_init(|| {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map_err(|e| format!("standard input: {}", e))?;
+
+        Ok(input)
+    }).as_deref()
+}
 
-    if !path.exists() {
-        println!("\t{}", "No validation file".bright_yellow());
+// Files are grouped by year, though 2022 files from before that are still found directly in the root.
+fn data_path(root: &str, year: u32, file: &str) -> PathBuf {
+    let path = Path::new(root).join(year.to_string()).join(file);
+    let legacy = Path::new(root).join(file);
+    if year == 2022 && !path.exists() && legacy.exists() {
+        legacy
     } else {
-        let expected = fs::read_to_string(path).unwrap();
+        path
+    }
+}
 
-        if actual == expected {
-            println!("\t{}", "Validated".green());
-        } else {
-            println!("{}", format!("Day {} part {} failed validation!\nExpected: {}\nActual: {}", day, part, expected, actual).red());
-        }
+fn output_path(year: u32, day: usize, part: usize) -> PathBuf {
+    data_path("output", year, &format!("part{}/day{}.out", part, day))
+}
+
+fn validate_part(year: u32, day: usize, part: usize, actual: &Answer) -> Validation {
+    let path = output_path(year, day, part);
+
+    match fs::read_to_string(&path) {
+        Ok(expected) if actual.matches(&expected) => Validation::Validated,
+        Ok(expected) => Validation::Failed { expected },
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Validation::NoFile,
+        Err(error) => Validation::Unreadable(format!("{}: {}", path.display(), error)),
+    }
+}
+
+fn record_part(year: u32, day: usize, part: usize, actual: &Answer, force: bool) -> Recording {
+    let path = output_path(year, day, part);
+
+    // Only a missing answer is safe to write without comparing it first.
+    match fs::read_to_string(&path) {
+        Ok(existing) if actual.matches(&existing) => return Recording::Unchanged,
+        Ok(existing) if !force => return Recording::Conflict { existing },
+        Ok(_) => {}
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+        Err(error) => return Recording::Failed(format!("{}: {}", path.display(), error)),
+    }
+
+    let written = path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&path, format!("{}\n", actual)));
+    match written {
+        Ok(()) => Recording::Recorded,
+        Err(error) => Recording::Failed(error.to_string()),
     }
 }
@@ -0,0 +1,102 @@
+use crate::day::SolveError;
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+
+// Glyphs are separated by a single blank column.
+const GLYPH_SPACING: usize = GLYPH_WIDTH + 1;
+
+// The letters the puzzles are known to draw; the font has no D, M, N, Q, T, V, W or X.
+const FONT: [(char, [&str; GLYPH_HEIGHT]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+// Reads the capital letters drawn in an image of lit pixels, refusing to guess at glyphs outside the font.
+pub fn read_letters<R: AsRef<[bool]>>(image: &[R]) -> Result<String, SolveError> {
+    if image.len() != GLYPH_HEIGHT {
+        return Err(SolveError::new(format!("Expected an image {} pixels high, got {}", GLYPH_HEIGHT, image.len())));
+    }
+
+    let width = image[0].as_ref().len();
+    if image.iter().any(|r| r.as_ref().len() != width) {
+        return Err(SolveError::new("Image rows differ in width"));
+    }
+
+    (0..width).step_by(GLYPH_SPACING)
+        .map(|left| {
+            let glyph = image.iter()
+                .map(|r| r.as_ref()[left..(left + GLYPH_WIDTH).min(width)].iter().map(|&p| if p { '#' } else { '.' }).collect::<String>())
+                .collect::<Vec<_>>();
+            FONT.iter()
+                .find(|(_, rows)| glyph.iter().eq(rows.iter()))
+                .map(|&(letter, _)| letter)
+                .ok_or_else(|| SolveError::new(format!("Unrecognised glyph at column {}:\n{}", left + 1, glyph.join("\n"))))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ocr::*;
+
+    fn image(rows: [&str; GLYPH_HEIGHT]) -> Vec<Vec<bool>> {
+        rows.map(|r| r.chars().map(|c| c == '#').collect()).to_vec()
+    }
+
+    #[test]
+    fn test_read_letters() {
+        let image = image([
+            "####.#..#.####.####.####.#..#..##..####.",
+            "#....#..#....#.#.......#.#..#.#..#....#.",
+            "###..####...#..###....#..####.#......#..",
+            "#....#..#..#...#.....#...#..#.#.....#...",
+            "#....#..#.#....#....#....#..#.#..#.#....",
+            "####.#..#.####.#....####.#..#..##..####.",
+        ]);
+
+        assert_eq!("EHZFZHCZ", read_letters(&image).unwrap());
+    }
+
+    #[test]
+    fn test_font() {
+        for (letter, rows) in FONT {
+            assert_eq!(letter.to_string(), read_letters(&image(rows)).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_unrecognised_glyph() {
+        let image = image([
+            "#..#.####",
+            "#..#.#..#",
+            "####.#..#",
+            "#..#.#..#",
+            "#..#.#..#",
+            "#..#.####",
+        ]);
+
+        let error = read_letters(&image).unwrap_err();
+        assert_eq!("Unrecognised glyph at column 6:\n####\n#..#\n#..#\n#..#\n#..#\n####", error.message);
+    }
+
+    #[test]
+    fn test_wrong_height() {
+        assert!(read_letters(&image([""; GLYPH_HEIGHT])[..5]).is_err());
+    }
+}
@@ -0,0 +1,218 @@
+use std::fmt::Write;
+use std::path::Path;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+impl Color {
+    pub const BLACK: Color = Color(0, 0, 0);
+    pub const WHITE: Color = Color(255, 255, 255);
+
+    // Blends towards `other` by a fraction clamped to between 0 and 1.
+    pub fn mix(self, other: Color, fraction: f64) -> Color {
+        let fraction = fraction.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * fraction).round() as u8;
+        Color(channel(self.0, other.0), channel(self.1, other.1), channel(self.2, other.2))
+    }
+}
+
+// A cell of a drawing, shown as its symbol by text backends and as its color by image backends.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Pixel {
+    pub symbol: char,
+    pub color: Color,
+}
+
+impl Pixel {
+    pub const fn new(symbol: char, color: Color) -> Self {
+        Self { symbol, color }
+    }
+}
+
+pub trait Canvas {
+    // Cells outside the canvas are ignored.
+    fn draw(&mut self, x: usize, y: usize, pixel: Pixel);
+
+    // Encodes the drawing in the format of the backend.
+    fn finish(&self) -> Vec<u8>;
+}
+
+pub trait Render {
+    // The width and height of the drawing in cells.
+    fn size(&self) -> (usize, usize);
+
+    fn render(&self, canvas: &mut dyn Canvas);
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Backend {
+    Ascii,
+    Ppm,
+    Svg,
+}
+
+// The width and height of a cell in image backends.
+const CELL_SIZE: usize = 4;
+
+impl Backend {
+    // Chosen by the extension of the file rendered to.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "txt" => Some(Backend::Ascii),
+            "ppm" => Some(Backend::Ppm),
+            "svg" => Some(Backend::Svg),
+            _ => None,
+        }
+    }
+
+    pub fn canvas(self, width: usize, height: usize) -> Box<dyn Canvas> {
+        match self {
+            Backend::Ascii => Box::new(AsciiCanvas { rows: vec![vec![' '; width]; height] }),
+            Backend::Ppm => Box::new(PpmCanvas { width, height, pixels: vec![Color::BLACK; width * height] }),
+            Backend::Svg => Box::new(SvgCanvas { width, height, cells: vec![None; width * height] }),
+        }
+    }
+
+    pub fn render(self, drawing: &dyn Render) -> Vec<u8> {
+        let (width, height) = drawing.size();
+        let mut canvas = self.canvas(width, height);
+        drawing.render(canvas.as_mut());
+        canvas.finish()
+    }
+}
+
+struct AsciiCanvas {
+    rows: Vec<Vec<char>>,
+}
+
+impl Canvas for AsciiCanvas {
+    fn draw(&mut self, x: usize, y: usize, pixel: Pixel) {
+        if let Some(cell) = self.rows.get_mut(y).and_then(|r| r.get_mut(x)) {
+            *cell = pixel.symbol;
+        }
+    }
+
+    fn finish(&self) -> Vec<u8> {
+        self.rows.iter()
+            .map(|r| r.iter().collect::<String>() + "\n")
+            .collect::<String>()
+            .into_bytes()
+    }
+}
+
+struct PpmCanvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<Color>,
+}
+
+impl Canvas for PpmCanvas {
+    fn draw(&mut self, x: usize, y: usize, pixel: Pixel) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = pixel.color;
+        }
+    }
+
+    // Binary PPM, with every cell scaled up to a square of pixels.
+    fn finish(&self) -> Vec<u8> {
+        let mut image = format!("P6\n{} {}\n255\n", self.width * CELL_SIZE, self.height * CELL_SIZE).into_bytes();
+        for row in self.pixels.chunks(self.width.max(1)).take(self.height) {
+            for _ in 0..CELL_SIZE {
+                for &Color(r, g, b) in row {
+                    for _ in 0..CELL_SIZE {
+                        image.extend([r, g, b]);
+                    }
+                }
+            }
+        }
+        image
+    }
+}
+
+struct SvgCanvas {
+    width: usize,
+    height: usize,
+    cells: Vec<Option<Color>>,
+}
+
+impl Canvas for SvgCanvas {
+    fn draw(&mut self, x: usize, y: usize, pixel: Pixel) {
+        if x < self.width && y < self.height {
+            self.cells[y * self.width + x] = Some(pixel.color);
+        }
+    }
+
+    // Undrawn cells are left transparent.
+    fn finish(&self) -> Vec<u8> {
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {2} {3}\" shape-rendering=\"crispEdges\">\n",
+            self.width * CELL_SIZE, self.height * CELL_SIZE, self.width, self.height,
+        );
+        for (i, cell) in self.cells.iter().enumerate() {
+            if let Some(Color(r, g, b)) = cell {
+                writeln!(svg, "<rect x=\"{}\" y=\"{}\" width=\"1\" height=\"1\" fill=\"#{:02x}{:02x}{:02x}\"/>", i % self.width, i / self.width, r, g, b).unwrap();
+            }
+        }
+        svg.push_str("</svg>\n");
+        svg.into_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::render::*;
+
+    struct Diagonal;
+
+    impl Render for Diagonal {
+        fn size(&self) -> (usize, usize) {
+            (2, 2)
+        }
+
+        fn render(&self, canvas: &mut dyn Canvas) {
+            canvas.draw(0, 0, Pixel::new('#', Color::WHITE));
+            canvas.draw(1, 1, Pixel::new('o', Color(255, 0, 16)));
+            canvas.draw(2, 0, Pixel::new('x', Color::WHITE));
+        }
+    }
+
+    #[test]
+    fn test_ascii() {
+        assert_eq!("# \n o\n", String::from_utf8(Backend::Ascii.render(&Diagonal)).unwrap());
+    }
+
+    #[test]
+    fn test_ppm() {
+        let image = Backend::Ppm.render(&Diagonal);
+        let header = b"P6\n8 8\n255\n";
+
+        assert_eq!(header, &image[..header.len()]);
+        assert_eq!(header.len() + 8 * 8 * 3, image.len());
+        assert_eq!([255, 255, 255, 0, 0, 0], image[header.len() + 3 * 3..header.len() + 5 * 3]);
+        assert_eq!([255, 0, 16], image[image.len() - 3..]);
+    }
+
+    #[test]
+    fn test_svg() {
+        let svg = String::from_utf8(Backend::Svg.render(&Diagonal)).unwrap();
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"8\" height=\"8\" viewBox=\"0 0 2 2\""));
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"1\" height=\"1\" fill=\"#ffffff\"/>"));
+        assert!(svg.contains("<rect x=\"1\" y=\"1\" width=\"1\" height=\"1\" fill=\"#ff0010\"/>"));
+        assert_eq!(2, svg.matches("<rect").count());
+    }
+
+    #[test]
+    fn test_backend_from_path() {
+        assert_eq!(Some(Backend::Svg), Backend::from_path(Path::new("out/day14.svg")));
+        assert_eq!(Some(Backend::Ascii), Backend::from_path(Path::new("day8.txt")));
+        assert_eq!(None, Backend::from_path(Path::new("day8.png")));
+        assert_eq!(None, Backend::from_path(Path::new("day8")));
+    }
+
+    #[test]
+    fn test_mix() {
+        assert_eq!(Color(128, 128, 128), Color::BLACK.mix(Color::WHITE, 0.5));
+        assert_eq!(Color::WHITE, Color::BLACK.mix(Color::WHITE, 2.0));
+    }
+}
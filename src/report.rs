@@ -0,0 +1,478 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use clap::ValueEnum;
+use colored::Colorize;
+use serde_json::{json, Value};
+
+use aoc2022_rs::answer::Answer;
+use aoc2022_rs::day::{DayReport, PartReport, SolveError, Statistics};
+
+pub enum RunError {
+    NoSolver,
+    NoInput,
+    Fetch(String),
+    Read(String),
+    Solve(SolveError),
+    Panic(String),
+}
+
+impl RunError {
+    pub fn describe(&self, day: usize) -> String {
+        match self {
+            RunError::NoSolver => format!("No solver for day {}", day),
+            RunError::NoInput => format!("Day {} has no input", day),
+            RunError::Fetch(error) => format!("Failed to fetch input for day {}: {}", day, error),
+            RunError::Read(error) => format!("Failed to read input for day {}: {}", day, error),
+            RunError::Solve(error) => error.to_string(),
+            RunError::Panic(message) => format!("Solver for day {} panicked: {}", day, message),
+        }
+    }
+}
+
+pub enum Validation {
+    Validated,
+    Failed { expected: String },
+    NoFile,
+    Unreadable(String),
+}
+
+pub enum Recording {
+    Recorded,
+    Unchanged,
+    Conflict { existing: String },
+    Failed(String),
+}
+
+pub struct DayRun {
+    pub day: usize,
+    pub outcome: Result<DayReport, RunError>,
+    pub validation: [Option<Validation>; 2],
+    pub recording: [Option<Recording>; 2],
+}
+
+impl DayRun {
+    pub fn parts(&self) -> Vec<(usize, &PartReport, &Option<Validation>, &Option<Recording>)> {
+        let Ok(report) = &self.outcome else {
+            return vec![];
+        };
+
+        [&report.part1, &report.part2].into_iter()
+            .zip(self.validation.iter().zip(&self.recording))
+            .enumerate()
+            .filter_map(|(i, (part, (validation, recording)))| part.as_ref().map(|p| (i + 1, p, validation, recording)))
+            .collect()
+    }
+
+    pub fn error(&self) -> Option<String> {
+        self.outcome.as_ref().err().map(|e| e.describe(self.day))
+    }
+
+    // The median when benchmarking, otherwise the single measured timing.
+    pub fn timing(&self) -> Option<Duration> {
+        self.outcome.as_ref().ok().and_then(|r| r.statistics.map(|s| s.median).or(r.total_timing))
+    }
+}
+
+pub fn part_timing(part: &PartReport) -> Option<Duration> {
+    part.statistics.map(|s| s.median).or(part.timing)
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn renderer(self, year: u32, validate: bool, timings: bool) -> Box<dyn Renderer> {
+        match self {
+            Format::Text => Box::new(TextRenderer { validate, timings }),
+            Format::Json => Box::new(JsonRenderer { year }),
+            Format::Csv => Box::new(CsvRenderer),
+        }
+    }
+}
+
+// Days are rendered as soon as they are solved, followed by a summary once all of them are done.
+pub trait Renderer {
+    fn render_header(&self) -> String {
+        String::new()
+    }
+
+    fn render_day(&self, run: &DayRun) -> String;
+
+    fn render_summary(&self, runs: &[DayRun], wall_time: Duration) -> String;
+}
+
+struct TextRenderer {
+    validate: bool,
+    timings: bool,
+}
+
+impl TextRenderer {
+    fn render_part(&self, out: &mut String, run: &DayRun, part: usize, report: &Option<PartReport>, has_part: bool) {
+        let day = run.day;
+        write!(out, "\tPart {}: ", part).unwrap();
+        let Some(report) = report else {
+            if !has_part {
+                writeln!(out, "{}", "No part 2".dimmed()).unwrap();
+            } else {
+                writeln!(out, "{}", "Not implemented".bright_yellow()).unwrap();
+            }
+            return;
+        };
+
+        let answer = match &report.result {
+            Ok(answer) => answer,
+            Err(error) => {
+                writeln!(out, "{}", format!("Error: {}", error).red()).unwrap();
+                return;
+            }
+        };
+
+        write!(out, "{}", present_answer(answer).blue()).unwrap();
+
+        if let Some(timing) = part_timing(report) {
+            if self.timings {
+                write!(out, " ({:?})", timing).unwrap();
+            }
+        }
+
+        writeln!(out).unwrap();
+
+        if let Some(statistics) = &report.statistics {
+            writeln!(out, "\t\t{}", present_statistics(statistics).dimmed()).unwrap();
+        }
+
+        if self.validate {
+            match &run.validation[part - 1] {
+                Some(Validation::Validated) => writeln!(out, "\t{}", "Validated".green()).unwrap(),
+                Some(Validation::Failed { expected }) => {
+                    let message = format!("Day {} part {} failed validation!\nExpected: {}\nActual: {}", day, part, expected.trim_end(), present_answer(answer));
+                    writeln!(out, "{}", message.red()).unwrap();
+                }
+                Some(Validation::NoFile) | None => writeln!(out, "\t{}", "No validation file".bright_yellow()).unwrap(),
+                Some(Validation::Unreadable(error)) => writeln!(out, "\t{}", format!("Failed to read validation file: {}", error).red()).unwrap(),
+            }
+        }
+
+        match &run.recording[part - 1] {
+            Some(Recording::Recorded) => writeln!(out, "\t{}", "Recorded".green()).unwrap(),
+            Some(Recording::Unchanged) => writeln!(out, "\t{}", "Already recorded".dimmed()).unwrap(),
+            Some(Recording::Conflict { existing }) => {
+                let message = format!("Day {} part {} differs from the recorded answer! Use --force to overwrite it.\nRecorded: {}", day, part, existing);
+                writeln!(out, "{}", message.red()).unwrap();
+            }
+            Some(Recording::Failed(error)) => writeln!(out, "\t{}", format!("Failed to record: {}", error).red()).unwrap(),
+            None => {}
+        }
+    }
+}
+
+impl Renderer for TextRenderer {
+    fn render_day(&self, run: &DayRun) -> String {
+        let mut out = String::new();
+        let report = match &run.outcome {
+            Ok(report) => report,
+            Err(RunError::NoSolver) => {
+                writeln!(out, "{}", format!("No solver for day {}", run.day).bright_yellow()).unwrap();
+                return out;
+            }
+            Err(RunError::NoInput) => {
+                writeln!(out, "{}", format!("Day {} has no input", run.day).red()).unwrap();
+                return out;
+            }
+            Err(error @ (RunError::Fetch(_) | RunError::Read(_))) => {
+                writeln!(out, "{}", error.describe(run.day).red()).unwrap();
+                return out;
+            }
+            Err(error @ (RunError::Solve(_) | RunError::Panic(_))) => {
+                writeln!(out, "Day {}", run.day).unwrap();
+                writeln!(out, "\t{}", format!("Error: {}", error.describe(run.day)).red()).unwrap();
+                return out;
+            }
+        };
+
+        write!(out, "Day {}", run.day).unwrap();
+
+        if let Some(total_timing) = run.timing() {
+            if self.timings {
+                write!(out, " ({:?})", total_timing).unwrap();
+            }
+        }
+
+        writeln!(out).unwrap();
+
+        if let Some(statistics) = &report.statistics {
+            writeln!(out, "\t{}", present_statistics(statistics).dimmed()).unwrap();
+        }
+
+        if let Some(parse_timing) = report.parse_statistics.map(|s| s.median).or(report.parse_timing) {
+            if self.timings {
+                writeln!(out, "\tParse: ({:?})", parse_timing).unwrap();
+            }
+        }
+
+        if let Some(statistics) = &report.parse_statistics {
+            writeln!(out, "\t\t{}", present_statistics(statistics).dimmed()).unwrap();
+        }
+
+        self.render_part(&mut out, run, 1, &report.part1, true);
+        self.render_part(&mut out, run, 2, &report.part2, report.has_part2);
+
+        out
+    }
+
+    fn render_summary(&self, runs: &[DayRun], wall_time: Duration) -> String {
+        if !self.timings || runs.len() < 2 {
+            return String::new();
+        }
+
+        let total = runs.iter().filter_map(DayRun::timing).sum::<Duration>();
+        format!("\nTotal time: {:?} (wall-clock {:?})\n", total, wall_time)
+    }
+}
+
+// Grids start on their own line so that their rows line up.
+pub fn present_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Grid(_) => format!("\n{}", answer),
+        _ => answer.to_string(),
+    }
+}
+
+fn present_statistics(statistics: &Statistics) -> String {
+    format!(
+        "min {:?}, median {:?}, mean {:?}, std dev {:?}, p95 {:?} ({} runs)",
+        statistics.min, statistics.median, statistics.mean, statistics.std_dev, statistics.p95, statistics.runs,
+    )
+}
+
+fn answer_json(answer: &Answer) -> Value {
+    match answer {
+        Answer::Integer(value) => json!(value),
+        Answer::Text(text) => json!(text),
+        Answer::Grid(rows) => json!(rows),
+    }
+}
+
+fn validation_status(validation: &Option<Validation>) -> Option<&'static str> {
+    validation.as_ref().map(|v| match v {
+        Validation::Validated => "validated",
+        Validation::Failed { .. } => "failed",
+        Validation::NoFile => "no_file",
+        Validation::Unreadable(_) => "unreadable",
+    })
+}
+
+fn recording_status(recording: &Option<Recording>) -> Option<&'static str> {
+    recording.as_ref().map(|r| match r {
+        Recording::Recorded => "recorded",
+        Recording::Unchanged => "unchanged",
+        Recording::Conflict { .. } => "conflict",
+        Recording::Failed(_) => "failed",
+    })
+}
+
+struct JsonRenderer {
+    year: u32,
+}
+
+impl JsonRenderer {
+    fn statistics(statistics: &Option<Statistics>) -> Value {
+        statistics.map_or(Value::Null, |s| json!({
+            "runs": s.runs,
+            "min_ns": s.min.as_nanos() as u64,
+            "median_ns": s.median.as_nanos() as u64,
+            "mean_ns": s.mean.as_nanos() as u64,
+            "std_dev_ns": s.std_dev.as_nanos() as u64,
+            "p95_ns": s.p95.as_nanos() as u64,
+        }))
+    }
+
+    fn day(run: &DayRun) -> Value {
+        let parts = run.parts().into_iter()
+            .map(|(part, report, validation, recording)| json!({
+                "part": part,
+                "answer": report.result.as_ref().ok().map(answer_json),
+                "error": report.result.as_ref().err().map(ToString::to_string),
+                "timing_ns": report.timing.map(|t| t.as_nanos() as u64),
+                "statistics": Self::statistics(&report.statistics),
+                "validation": validation_status(validation),
+                "expected": match validation {
+                    Some(Validation::Failed { expected }) => Some(expected),
+                    _ => None,
+                },
+                "recording": recording_status(recording),
+            }))
+            .collect::<Vec<_>>();
+
+        let report = run.outcome.as_ref().ok();
+        json!({
+            "day": run.day,
+            "error": run.error(),
+            "timing_ns": report.and_then(|r| r.total_timing).map(|t| t.as_nanos() as u64),
+            "statistics": Self::statistics(&report.and_then(|r| r.statistics)),
+            "parse_timing_ns": report.and_then(|r| r.parse_timing).map(|t| t.as_nanos() as u64),
+            "parse_statistics": Self::statistics(&report.and_then(|r| r.parse_statistics)),
+            "parts": parts,
+        })
+    }
+}
+
+impl Renderer for JsonRenderer {
+    fn render_day(&self, _: &DayRun) -> String {
+        String::new()
+    }
+
+    fn render_summary(&self, runs: &[DayRun], wall_time: Duration) -> String {
+        let document = json!({
+            "year": self.year,
+            "days": runs.iter().map(Self::day).collect::<Vec<_>>(),
+            "total_timing_ns": runs.iter().filter_map(DayRun::timing).sum::<Duration>().as_nanos() as u64,
+            "wall_time_ns": wall_time.as_nanos() as u64,
+        });
+        format!("{:#}\n", document)
+    }
+}
+
+struct CsvRenderer;
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+fn csv_nanos(duration: Option<Duration>) -> String {
+    duration.map_or(String::new(), |d| d.as_nanos().to_string())
+}
+
+impl Renderer for CsvRenderer {
+    fn render_header(&self) -> String {
+        "day,part,answer,timing_ns,parse_timing_ns,day_timing_ns,runs,min_ns,median_ns,mean_ns,std_dev_ns,p95_ns,validation,error\n".to_owned()
+    }
+
+    fn render_day(&self, run: &DayRun) -> String {
+        if let Some(error) = run.error() {
+            return format!("{},,,,,,,,,,,,,{}\n", run.day, csv_field(&error));
+        }
+
+        let report = run.outcome.as_ref().ok();
+        let parse_timing = csv_nanos(report.and_then(|r| r.parse_timing));
+        let day_timing = csv_nanos(report.and_then(|r| r.total_timing));
+        run.parts().into_iter()
+            .map(|(part, report, validation, _)| {
+                let statistics = match &report.statistics {
+                    Some(s) => format!(
+                        "{},{},{},{},{},{}",
+                        s.runs, s.min.as_nanos(), s.median.as_nanos(), s.mean.as_nanos(), s.std_dev.as_nanos(), s.p95.as_nanos(),
+                    ),
+                    None => ",,,,,".to_owned(),
+                };
+                format!(
+                    "{},{},{},{},{},{},{},{},{}\n",
+                    run.day, part, csv_field(&report.result.as_ref().map_or(String::new(), ToString::to_string)), csv_nanos(report.timing),
+                    parse_timing, day_timing, statistics, validation_status(validation).unwrap_or_default(),
+                    csv_field(&report.result.as_ref().err().map_or(String::new(), ToString::to_string)),
+                )
+            })
+            .collect()
+    }
+
+    fn render_summary(&self, _: &[DayRun], _: Duration) -> String {
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::*;
+
+    fn run() -> DayRun {
+        DayRun {
+            day: 10,
+            outcome: Ok(DayReport {
+                parse_timing: Some(Duration::from_nanos(500)),
+                parse_statistics: None,
+                part1: Some(PartReport { result: Ok(Answer::Integer(13140)), timing: Some(Duration::from_nanos(1500)), statistics: None }),
+                part2: Some(PartReport { result: Ok(Answer::Grid(vec!["#.".to_owned(), ".#".to_owned()])), timing: None, statistics: None }),
+                has_part2: true,
+                total_timing: Some(Duration::from_nanos(2500)),
+                statistics: None,
+            }),
+            validation: [Some(Validation::Validated), Some(Validation::Failed { expected: "x".to_owned() })],
+            recording: [Some(Recording::Unchanged), None],
+        }
+    }
+
+    #[test]
+    fn test_csv() {
+        let csv = CsvRenderer.render_day(&run());
+        assert_eq!("10,1,13140,1500,500,2500,,,,,,,validated,\n10,2,\"#.\n.#\",,500,2500,,,,,,,failed,\n", csv);
+    }
+
+    #[test]
+    fn test_text_without_part2() {
+        let mut run = run();
+        if let Ok(report) = &mut run.outcome {
+            report.part2 = None;
+            report.has_part2 = false;
+        }
+
+        let text = TextRenderer { validate: false, timings: false }.render_day(&run);
+        assert!(text.contains("Part 2: ") && text.contains("No part 2"));
+        assert!(!text.contains("Not implemented"));
+    }
+
+    #[test]
+    fn test_part_error() {
+        let mut run = run();
+        if let Ok(report) = &mut run.outcome {
+            report.part2.as_mut().unwrap().result = Err(SolveError::new("Unrecognised glyph"));
+        }
+        run.validation[1] = None;
+
+        let csv = CsvRenderer.render_day(&run);
+        assert_eq!("10,1,13140,1500,500,2500,,,,,,,validated,\n10,2,,,500,2500,,,,,,,,Unrecognised glyph\n", csv);
+
+        let text = TextRenderer { validate: true, timings: false }.render_day(&run);
+        assert!(text.contains("13140") && text.contains("Validated") && text.contains("Error: Unrecognised glyph"));
+    }
+
+    #[test]
+    fn test_csv_error() {
+        let run = DayRun { day: 3, outcome: Err(RunError::NoInput), validation: [None, None], recording: [None, None] };
+        assert_eq!("3,,,,,,,,,,,,,Day 3 has no input\n", CsvRenderer.render_day(&run));
+    }
+
+    #[test]
+    fn test_text_run_errors() {
+        let text = |error| TextRenderer { validate: true, timings: false }
+            .render_day(&DayRun { day: 3, outcome: Err(error), validation: [None, None], recording: [None, None] });
+        assert!(text(RunError::Read("day3.in: Permission denied".to_owned())).contains("Failed to read input for day 3: day3.in: Permission denied"));
+        assert!(text(RunError::Panic("index out of bounds".to_owned())).contains("Error: Solver for day 3 panicked: index out of bounds"));
+    }
+
+    #[test]
+    fn test_json() {
+        let document: Value = serde_json::from_str(&JsonRenderer { year: 2022 }.render_summary(&[run()], Duration::from_nanos(3000))).unwrap();
+        assert_eq!(2022, document["year"]);
+        assert_eq!(2500, document["total_timing_ns"]);
+        assert_eq!(3000, document["wall_time_ns"]);
+        assert_eq!(10, document["days"][0]["day"]);
+        assert_eq!(500, document["days"][0]["parse_timing_ns"]);
+        assert_eq!(13140, document["days"][0]["parts"][0]["answer"]);
+        assert_eq!(json!(["#.", ".#"]), document["days"][0]["parts"][1]["answer"]);
+        assert_eq!(1500, document["days"][0]["parts"][0]["timing_ns"]);
+        assert_eq!("failed", document["days"][0]["parts"][1]["validation"]);
+        assert_eq!("x", document["days"][0]["parts"][1]["expected"]);
+        assert_eq!("unchanged", document["days"][0]["parts"][0]["recording"]);
+        assert_eq!(Value::Null, document["days"][0]["parts"][1]["recording"]);
+        assert_eq!(Value::Null, document["days"][0]["error"]);
+    }
+}
@@ -0,0 +1,350 @@
+use std::collections::BTreeSet;
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::{self, Scope};
+use std::time::Duration;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+
+use aoc2022_rs::answer::Answer;
+use aoc2022_rs::fetch::Fetcher;
+use aoc2022_rs::years;
+
+use crate::report::{part_timing, DayRun, Validation};
+use crate::{output_path, run_day, Args};
+
+const DAYS: usize = 25;
+const CALENDAR_COLUMNS: usize = 5;
+const CELL_WIDTH: u16 = 4;
+
+// How often finished runs are picked up while no key is pressed.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Status {
+    Missing,
+    Implemented,
+    Recorded,
+    Validated,
+    Failed,
+    Error,
+}
+
+impl Status {
+    // Before a day has been run, its status is all that can be told from the registry and the output store.
+    fn stored(year: u32, day: usize) -> Self {
+        if years::get(year, day).is_none() {
+            Status::Missing
+        } else if (1..=2).any(|part| output_path(year, day, part).exists()) {
+            Status::Recorded
+        } else {
+            Status::Implemented
+        }
+    }
+
+    fn of_run(run: &DayRun, stored: Status) -> Self {
+        if run.outcome.is_err() || run.parts().iter().any(|(_, report, ..)| report.result.is_err()) {
+            return Status::Error;
+        }
+
+        let validations = run.validation.iter().flatten().collect::<Vec<_>>();
+        if validations.iter().any(|v| matches!(v, Validation::Unreadable(_))) {
+            return Status::Error;
+        }
+
+        if validations.iter().any(|v| matches!(v, Validation::Failed { .. })) {
+            Status::Failed
+        } else if !validations.is_empty() && validations.iter().all(|v| matches!(v, Validation::Validated)) {
+            Status::Validated
+        } else {
+            stored
+        }
+    }
+
+    fn style(self) -> Style {
+        match self {
+            Status::Missing => Style::new().fg(Color::DarkGray),
+            Status::Implemented => Style::new().fg(Color::Yellow),
+            Status::Recorded => Style::new().fg(Color::Blue),
+            Status::Validated => Style::new().fg(Color::Green),
+            Status::Failed => Style::new().fg(Color::Red),
+            Status::Error => Style::new().fg(Color::Magenta),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum DiffLine<'a> {
+    Same(&'a str),
+    Expected(&'a str),
+    Actual(&'a str),
+}
+
+// Compares line by line, which is enough to spot the differing rows of a picture.
+fn diff<'a>(expected: &'a str, actual: &'a str) -> Vec<DiffLine<'a>> {
+    let mut expected = expected.trim_matches(['\r', '\n']).lines().map(str::trim_end);
+    let mut actual = actual.lines();
+    let mut lines = vec![];
+    loop {
+        match (expected.next(), actual.next()) {
+            (None, None) => return lines,
+            (Some(e), Some(a)) if e == a => lines.push(DiffLine::Same(e)),
+            (e, a) => {
+                lines.extend(e.map(DiffLine::Expected));
+                lines.extend(a.map(DiffLine::Actual));
+            }
+        }
+    }
+}
+
+struct App<'a> {
+    args: &'a Args,
+    // Set on quitting, so that background runs stop after the day they are solving.
+    stopped: &'a AtomicBool,
+    selected: usize,
+    stored: [Status; DAYS],
+    runs: Vec<Option<DayRun>>,
+    running: BTreeSet<usize>,
+}
+
+pub fn run(args: &Args, fetcher: Option<&Fetcher>) -> io::Result<()> {
+    let stopped = AtomicBool::new(false);
+    let mut app = App {
+        args,
+        stopped: &stopped,
+        selected: args.day.unwrap_or(1),
+        stored: std::array::from_fn(|i| Status::stored(args.year, i + 1)),
+        runs: (0..DAYS).map(|_| None).collect(),
+        running: BTreeSet::new(),
+    };
+
+    // Setting up can fail halfway, such as without a terminal to draw on, leaving raw mode enabled.
+    let mut terminal = ratatui::try_init().inspect_err(|_| ratatui::restore())?;
+    thread::scope(|scope| {
+        let result = app.event_loop(&mut terminal, scope, fetcher);
+        // The scope still waits for the days being solved to finish, but the terminal is given back meanwhile.
+        stopped.store(true, Ordering::Relaxed);
+        ratatui::restore();
+        result
+    })
+}
+
+impl<'a> App<'a> {
+    fn event_loop<'scope>(&mut self, terminal: &mut DefaultTerminal, scope: &'scope Scope<'scope, '_>, fetcher: Option<&'scope Fetcher>) -> io::Result<()>
+        where 'a: 'scope {
+        let (sender, receiver) = mpsc::channel();
+        loop {
+            self.receive(&receiver);
+            terminal.draw(|frame| self.draw(frame))?;
+
+            if !event::poll(POLL_INTERVAL)? {
+                continue;
+            }
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Left | KeyCode::Char('h') => self.select(-1),
+                KeyCode::Right | KeyCode::Char('l') => self.select(1),
+                KeyCode::Up | KeyCode::Char('k') => self.select(-(CALENDAR_COLUMNS as isize)),
+                KeyCode::Down | KeyCode::Char('j') => self.select(CALENDAR_COLUMNS as isize),
+                KeyCode::Enter | KeyCode::Char('r') => self.solve(vec![self.selected], scope, &sender, fetcher),
+                KeyCode::Char('a') => {
+                    let days = (1..=DAYS).filter(|&d| self.stored[d - 1] != Status::Missing).collect();
+                    self.solve(days, scope, &sender, fetcher);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn select(&mut self, offset: isize) {
+        self.selected = self.selected.saturating_add_signed(offset).clamp(1, DAYS);
+    }
+
+    // Runs the days one after another in the background, skipping those already being solved.
+    fn solve<'scope>(&mut self, days: Vec<usize>, scope: &'scope Scope<'scope, '_>, sender: &Sender<DayRun>, fetcher: Option<&'scope Fetcher>)
+        where 'a: 'scope {
+        let days = days.into_iter().filter(|&d| self.running.insert(d)).collect::<Vec<_>>();
+        let args = self.args;
+        let stopped = self.stopped;
+        let sender = sender.clone();
+        scope.spawn(move || {
+            for day in days {
+                if stopped.load(Ordering::Relaxed) || sender.send(run_day(day, args, fetcher)).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    fn receive(&mut self, receiver: &Receiver<DayRun>) {
+        while let Ok(run) = receiver.try_recv() {
+            self.running.remove(&run.day);
+            let day = run.day;
+            self.runs[day - 1] = Some(run);
+        }
+    }
+
+    fn status(&self, day: usize) -> Status {
+        let stored = self.stored[day - 1];
+        self.runs[day - 1].as_ref().map_or(stored, |run| Status::of_run(run, stored))
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let calendar_width = CELL_WIDTH * CALENDAR_COLUMNS as u16 + 2;
+        let [sidebar, details] = Layout::horizontal([Constraint::Length(calendar_width), Constraint::Min(0)]).areas(frame.area());
+        let [calendar, legend] = Layout::vertical([Constraint::Length((DAYS / CALENDAR_COLUMNS) as u16 + 2), Constraint::Min(0)]).areas(sidebar);
+
+        frame.render_widget(Paragraph::new(self.calendar()).block(Block::bordered().title(self.args.year.to_string())), calendar);
+        frame.render_widget(Paragraph::new(legend_lines()).block(Block::bordered()), legend);
+        frame.render_widget(
+            Paragraph::new(self.details()).wrap(Wrap { trim: false }).block(Block::bordered().title(format!("Day {}", self.selected))),
+            details,
+        );
+    }
+
+    fn calendar(&self) -> Vec<Line<'static>> {
+        (1..=DAYS).collect::<Vec<_>>()
+            .chunks(CALENDAR_COLUMNS)
+            .map(|week| Line::from(week.iter()
+                .map(|&day| {
+                    let mut style = self.status(day).style();
+                    if day == self.selected {
+                        style = style.add_modifier(Modifier::REVERSED);
+                    }
+                    let marker = if self.running.contains(&day) { "*" } else { " " };
+                    Span::styled(format!("{:>3}{}", day, marker), style)
+                })
+                .collect::<Vec<_>>()))
+            .collect()
+    }
+
+    fn details(&self) -> Vec<Line<'_>> {
+        let day = self.selected;
+        if self.running.contains(&day) {
+            return vec![Line::from("Solving...".yellow())];
+        }
+        let Some(run) = &self.runs[day - 1] else {
+            return match self.stored[day - 1] {
+                Status::Missing => vec![Line::from("Not implemented".dark_gray())],
+                _ => vec![Line::from("Press Enter to solve".dim())],
+            };
+        };
+        if let Some(error) = run.error() {
+            return vec![Line::from(error.red())];
+        }
+
+        let mut lines = vec![];
+        for (part, report, validation, _) in run.parts() {
+            let timing = part_timing(report).map_or(String::new(), |t| format!(" ({:?})", t));
+            match &report.result {
+                Err(error) => {
+                    lines.push(Line::from(vec![Span::raw(format!("Part {}: ", part)), format!("Error: {}", error).red()]));
+                    lines.push(Line::default());
+                    continue;
+                }
+                Ok(Answer::Grid(rows)) => {
+                    lines.push(Line::from(format!("Part {}:{}", part, timing)));
+                    lines.extend(rows.iter().map(|r| Line::from(r.clone().blue())));
+                }
+                Ok(answer) => lines.push(Line::from(vec![
+                    Span::raw(format!("Part {}: ", part)),
+                    answer.to_string().blue(),
+                    Span::raw(timing),
+                ])),
+            }
+
+            match validation {
+                Some(Validation::Validated) => lines.push(Line::from("Validated".green())),
+                Some(Validation::NoFile) | None => lines.push(Line::from("No validation file".yellow())),
+                Some(Validation::Unreadable(error)) => lines.push(Line::from(format!("Failed to read validation file: {}", error).red())),
+                Some(Validation::Failed { expected }) => {
+                    lines.push(Line::from("Failed validation:".red()));
+                    let actual = report.result.as_ref().map_or(String::new(), ToString::to_string);
+                    lines.extend(diff(expected, &actual).into_iter().map(|line| match line {
+                        DiffLine::Same(l) => Line::from(format!("  {}", l)),
+                        DiffLine::Expected(l) => Line::from(format!("- {}", l).red()),
+                        DiffLine::Actual(l) => Line::from(format!("+ {}", l).green()),
+                    }));
+                }
+            }
+            lines.push(Line::default());
+        }
+
+        if let Ok(report) = &run.outcome {
+            if let Some(parse_timing) = report.parse_timing {
+                lines.push(Line::from(format!("Parse: {:?}", parse_timing).dim()));
+            }
+        }
+        if let Some(timing) = run.timing() {
+            lines.push(Line::from(format!("Total: {:?}", timing).dim()));
+        }
+        lines
+    }
+}
+
+fn legend_lines() -> Vec<Line<'static>> {
+    let statuses = [
+        (Status::Validated, "Validated"),
+        (Status::Failed, "Failed"),
+        (Status::Error, "Error"),
+        (Status::Recorded, "Recorded"),
+        (Status::Implemented, "Not recorded"),
+        (Status::Missing, "Not implemented"),
+    ];
+
+    statuses.into_iter().map(|(status, label)| Line::styled(label, status.style())).chain([
+        Line::default(),
+        Line::from("Arrows: select".dim()),
+        Line::from("Enter: solve".dim()),
+        Line::from("a: solve all".dim()),
+        Line::from("q: quit".dim()),
+    ]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022_rs::day::{DayReport, PartReport};
+
+    use crate::report::RunError;
+    use crate::tui::*;
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(
+            vec![DiffLine::Same("#."), DiffLine::Expected(".#"), DiffLine::Actual("##"), DiffLine::Actual("..")],
+            diff("\n#.\n.#\n", "#.\n##\n.."),
+        );
+        assert_eq!(vec![DiffLine::Expected("42"), DiffLine::Actual("43")], diff("42\n", "43"));
+    }
+
+    fn run(validation: [Option<Validation>; 2]) -> DayRun {
+        let part = || Some(PartReport { result: Ok(Answer::Integer(1)), timing: None, statistics: None });
+        let report = DayReport { parse_timing: None, parse_statistics: None, part1: part(), part2: part(), has_part2: true, total_timing: None, statistics: None };
+        DayRun { day: 1, outcome: Ok(report), validation, recording: [None, None] }
+    }
+
+    #[test]
+    fn test_status_of_run() {
+        let failed = || Some(Validation::Failed { expected: "2".to_owned() });
+
+        assert_eq!(Status::Validated, Status::of_run(&run([Some(Validation::Validated), Some(Validation::Validated)]), Status::Recorded));
+        assert_eq!(Status::Failed, Status::of_run(&run([Some(Validation::Validated), failed()]), Status::Recorded));
+        assert_eq!(Status::Implemented, Status::of_run(&run([Some(Validation::NoFile), None]), Status::Implemented));
+
+        let error = DayRun { day: 1, outcome: Err(RunError::NoInput), validation: [None, None], recording: [None, None] };
+        assert_eq!(Status::Error, Status::of_run(&error, Status::Recorded));
+    }
+}
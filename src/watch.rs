@@ -0,0 +1,213 @@
+use std::collections::{BTreeSet, HashMap};
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+use colored::Colorize;
+use notify::{Event, RecursiveMode, Watcher};
+
+use aoc2022_rs::fetch::Fetcher;
+
+use crate::report::{DayRun, Renderer};
+use crate::{input_path, output_path, run_days, Args};
+
+// Editors tend to save a file through several events, which should only cause a single run.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+// The files each day depends on. Their directories are watched rather than the files themselves,
+// as editors often replace a file instead of writing to it, so they are kept by canonical path.
+// Several days can share a file, such as an input given with --file.
+struct WatchedFiles(HashMap<PathBuf, BTreeSet<usize>>);
+
+impl WatchedFiles {
+    fn new(days: &[usize], args: &Args) -> Self {
+        let mut files = HashMap::<_, BTreeSet<_>>::new();
+        for &day in days {
+            for path in [input_path(day, args), output_path(args.year, day, 1), output_path(args.year, day, 2)] {
+                if let Some(path) = canonical(&path) {
+                    files.entry(path).or_default().insert(day);
+                }
+            }
+        }
+        Self(files)
+    }
+
+    // The nearest existing directory of each file, which gives way to the file's own directory once that is created.
+    fn directories(&self) -> BTreeSet<PathBuf> {
+        self.0.keys()
+            .filter_map(|p| p.ancestors().skip(1).find(|a| a.is_dir()))
+            .map(Path::to_owned)
+            .collect()
+    }
+
+    // Creating a directory can bring the files in it along, as with mkdir -p.
+    fn days(&self, paths: &[PathBuf], created: bool) -> BTreeSet<usize> {
+        let paths = paths.iter().flat_map(|p| [Some(p.clone()), canonical(p)]).flatten().collect::<Vec<_>>();
+        self.0.iter()
+            .filter(|(file, _)| paths.iter().any(|p| *file == p || created && file.starts_with(p)))
+            .flat_map(|(_, days)| days)
+            .copied()
+            .collect()
+    }
+}
+
+// Files that do not exist yet are resolved through their nearest existing ancestor, so they are found again once created.
+fn canonical(path: &Path) -> Option<PathBuf> {
+    let path = std::path::absolute(path).ok()?;
+    path.ancestors()
+        .find_map(|a| Some(a.canonicalize().ok()?.join(path.strip_prefix(a).ok()?)))
+}
+
+// Directories created since the last call are watched from now on, and the files already in them count as changed.
+fn watch_directories(watcher: &mut dyn Watcher, files: &WatchedFiles, watched: &mut BTreeSet<PathBuf>) -> Result<BTreeSet<usize>, String> {
+    let mut days = BTreeSet::new();
+    for directory in files.directories() {
+        if watched.contains(&directory) {
+            continue;
+        }
+
+        watcher.watch(&directory, RecursiveMode::NonRecursive)
+            .map_err(|e| format!("Failed to watch {}: {}", directory.display(), e))?;
+        days.extend(files.0.iter()
+            .filter(|(file, _)| file.parent() == Some(&directory) && file.exists())
+            .flat_map(|(_, days)| days));
+        watched.insert(directory);
+    }
+    Ok(days)
+}
+
+// Waits for a change to any of the files, then for the burst of events it is part of to die down.
+fn next_changes(events: &Receiver<notify::Result<Event>>, files: &WatchedFiles, debounce: Duration) -> Option<BTreeSet<usize>> {
+    let mut days = BTreeSet::new();
+    loop {
+        let event = if days.is_empty() {
+            events.recv().ok()?
+        } else {
+            match events.recv_timeout(debounce) {
+                Ok(event) => event,
+                Err(RecvTimeoutError::Timeout | RecvTimeoutError::Disconnected) => return Some(days),
+            }
+        };
+
+        if let Ok(event) = event {
+            if !event.kind.is_access() {
+                days.extend(files.days(&event.paths, event.kind.is_create()));
+            }
+        }
+    }
+}
+
+pub fn watch(days: &[usize], args: &Args, fetcher: Option<&Fetcher>, renderer: &dyn Renderer) -> Result<(), String> {
+    let files = WatchedFiles::new(days, args);
+    let (sender, events) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender).map_err(|e| format!("Failed to start watching: {}", e))?;
+    let mut watched = BTreeSet::new();
+    watch_directories(&mut watcher, &files, &mut watched)?;
+
+    let mut runs = vec![];
+    let mut changed = days.iter().copied().collect::<BTreeSet<_>>();
+    loop {
+        let start = Instant::now();
+        run_days(&changed.into_iter().collect::<Vec<_>>(), args, fetcher, |run| {
+            match runs.binary_search_by_key(&run.day, |r: &DayRun| r.day) {
+                Ok(i) => runs[i] = run,
+                Err(i) => runs.insert(i, run),
+            }
+        });
+        redraw(&runs, renderer, start.elapsed());
+
+        changed = next_changes(&events, &files, DEBOUNCE).ok_or("Stopped watching")?;
+        changed.extend(watch_directories(&mut watcher, &files, &mut watched)?);
+    }
+}
+
+fn redraw(runs: &[DayRun], renderer: &dyn Renderer, wall_time: Duration) {
+    if io::stdout().is_terminal() {
+        print!("\x1B[2J\x1B[H");
+    }
+
+    print!("{}", renderer.render_header());
+    for run in runs {
+        print!("{}", renderer.render_day(run));
+    }
+    print!("{}", renderer.render_summary(runs, wall_time));
+    // Kept out of standard output so that JSON and CSV reports stay parseable.
+    eprintln!("{}", "Watching for changes...".dimmed());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, slice, thread};
+
+    use notify::EventKind;
+    use notify::event::{AccessKind, ModifyKind};
+
+    use crate::watch::*;
+
+    fn files() -> (PathBuf, WatchedFiles) {
+        let directory = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let directory = directory.canonicalize().unwrap();
+        let files = WatchedFiles([
+            (directory.join("day1.in"), BTreeSet::from([1])),
+            (directory.join("day2.in"), BTreeSet::from([2])),
+            (directory.join("shared.in"), BTreeSet::from([3, 4])),
+        ].into_iter().collect());
+        (directory, files)
+    }
+
+    #[test]
+    fn test_days() {
+        let (directory, files) = files();
+
+        assert_eq!(BTreeSet::from([2]), files.days(&[directory.join("day2.in"), directory.join("day3.in")], false));
+        assert_eq!(BTreeSet::from([directory]), files.directories());
+    }
+
+    #[test]
+    fn test_shared_file() {
+        let (directory, files) = files();
+
+        assert_eq!(BTreeSet::from([3, 4]), files.days(&[directory.join("shared.in")], false));
+        assert_eq!(BTreeSet::from([1, 3, 4]), files.days(&[directory.join("shared.in"), directory.join("day1.in")], false));
+    }
+
+    #[test]
+    fn test_missing_directory() {
+        let (directory, mut files) = files();
+        let missing = directory.join("missing");
+        let path = canonical(&missing.join("day5.in")).unwrap();
+        files.0.insert(path.clone(), BTreeSet::from([5]));
+
+        assert_eq!(missing.join("day5.in"), path);
+        assert_eq!(BTreeSet::from([directory]), files.directories());
+        assert_eq!(BTreeSet::new(), files.days(slice::from_ref(&missing), false));
+        assert_eq!(BTreeSet::from([5]), files.days(&[missing], true));
+    }
+
+    #[test]
+    fn test_debounce() {
+        let (directory, files) = files();
+        let (sender, events) = mpsc::channel();
+
+        let modified = |file: &str| Ok(Event::new(EventKind::Modify(ModifyKind::Any)).add_path(directory.join(file)));
+        sender.send(Ok(Event::new(EventKind::Access(AccessKind::Any)).add_path(directory.join("day2.in")))).unwrap();
+        sender.send(modified("day1.in")).unwrap();
+        let burst = thread::spawn({
+            let sender = sender.clone();
+            let modified = modified("day2.in");
+            move || {
+                thread::sleep(Duration::from_millis(20));
+                sender.send(modified).unwrap();
+            }
+        });
+
+        assert_eq!(Some(BTreeSet::from([1, 2])), next_changes(&events, &files, Duration::from_millis(200)));
+        burst.join().unwrap();
+
+        sender.send(modified("other.txt")).unwrap();
+        drop(sender);
+        assert_eq!(None, next_changes(&events, &files, Duration::from_millis(200)));
+    }
+}
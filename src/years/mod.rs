@@ -0,0 +1,34 @@
+use crate::day::Day;
+
+mod year2022;
+
+pub const DEFAULT_YEAR: u32 = 2022;
+
+static YEARS: [(u32, [Option<Day>; 25]); 1] = [
+    (2022, year2022::DAYS),
+];
+
+pub fn has_year(year: u32) -> bool {
+    YEARS.iter().any(|&(y, _)| y == year)
+}
+
+pub fn get(year: u32, day: usize) -> Option<&'static Day> {
+    YEARS.iter()
+        .find(|&&(y, _)| y == year)
+        .and_then(|(_, days)| days.get(day.checked_sub(1)?))
+        .and_then(Option::as_ref)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::years::*;
+
+    #[test]
+    fn test_get() {
+        assert!(get(2022, 1).is_some());
+        assert!(get(2022, 25).is_some());
+        assert!(get(2022, 0).is_none());
+        assert!(get(2022, 26).is_none());
+        assert!(get(2015, 1).is_none());
+    }
+}
@@ -0,0 +1,62 @@
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, parse, SecondPart, SolveError, Solution};
+
+pub const DAY_1: Day = Day {
+    solver: DaySolver::new::<Day1>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("24000"), Some("45000")),
+    ],
+};
+
+struct Day1;
+
+impl Solution for Day1 {
+    // The calories carried by each elf, most first.
+    type Parsed<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        let mut sums = input
+            .split("\n\n")
+            .map(|e| e.lines().map(parse::<i32>).sum::<Result<i32, _>>())
+            .collect::<Result<Vec<_>, _>>()?;
+        sums.sort_by(|a, b| b.cmp(a));
+        Ok(sums)
+    }
+
+    fn part1(sums: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok((*sums.first().ok_or_else(|| SolveError::new("No elves"))?).into())
+    }
+}
+
+impl SecondPart for Day1 {
+    fn part2(sums: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(sums.iter().take(3).sum::<i32>().into())
+    }
+}
+
+const EXAMPLE_INPUT: &str = "\
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day1::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_1);
+    }
+}
@@ -0,0 +1,165 @@
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, Parameters, parse, SecondPart, SolveError, Solution};
+use crate::ocr::read_letters;
+use crate::years::year2022::day10::Command::*;
+
+pub const DAY_10: Day = Day {
+    solver: DaySolver::new::<Day10>(),
+    examples: &[
+        // The example draws a test pattern rather than letters.
+        Example::new(EXAMPLE_INPUT, Some("13140"), Some(EXAMPLE_OUTPUT_2)).with_parameters(&[(Parameters::RAW_NAME, 1)]),
+    ],
+};
+
+#[derive(Copy, Clone, Debug)]
+enum Command {
+    Noop,
+    AddX(i32),
+}
+
+impl TryFrom<&str> for Command {
+    type Error = SolveError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        if s == "noop" {
+            Ok(Noop)
+        } else if let Some(value) = s.strip_prefix("addx ") {
+            Ok(AddX(parse(value)?))
+        } else {
+            Err(SolveError::at(s, "Invalid command"))
+        }
+    }
+}
+
+impl Command {
+    fn cycles(self) -> usize {
+        match self {
+            Noop => 1,
+            AddX(_) => 2,
+        }
+    }
+}
+
+// Calls `during_cycle` with each cycle number, starting from 1, and the value of X during it.
+fn execute<F: FnMut(usize, i32)>(commands: &[Command], mut during_cycle: F) {
+    let mut x = 1i32;
+    let mut cycles = 1;
+    for &command in commands {
+        for cycle in cycles..cycles + command.cycles() {
+            during_cycle(cycle, x);
+        }
+
+        match command {
+            Noop => {}
+            AddX(v) => { x += v }
+        }
+        cycles += command.cycles();
+    }
+}
+
+struct Program {
+    commands: Vec<Command>,
+    // Whether to answer part 2 with the image itself instead of the letters it shows.
+    raw: bool,
+}
+
+struct Day10;
+
+impl Solution for Day10 {
+    type Parsed<'a> = Program;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Self::parse_with(input, &Parameters::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, parameters: &Parameters) -> Result<Self::Parsed<'a>, SolveError> {
+        Ok(Program {
+            commands: input.lines().map(Command::try_from).collect::<Result<_, _>>()?,
+            raw: parameters.get(Parameters::RAW_NAME).is_some_and(|r| r != 0),
+        })
+    }
+
+    fn part1(Program { commands, .. }: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        let mut next_interesting = 20;
+        let mut signal_strengths = 0;
+        execute(commands, |cycle, x| {
+            if cycle == next_interesting {
+                signal_strengths += next_interesting * x as usize;
+                next_interesting += 40;
+            }
+        });
+        Ok(signal_strengths.into())
+    }
+}
+
+impl SecondPart for Day10 {
+    fn part2(Program { commands, raw }: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        const WIDTH: usize = 40;
+        const HEIGHT: usize = 6;
+
+        let mut image = [[false; WIDTH]; HEIGHT];
+        execute(commands, |cycle, x| {
+            let (row, col) = ((cycle - 1) / WIDTH, (cycle - 1) % WIDTH);
+            if row < HEIGHT && x - 1 <= col as i32 && col as i32 <= x + 1 {
+                image[row][col] = true;
+            }
+        });
+
+        if *raw {
+            Ok(Answer::Grid(image
+                .map(|r| r.map(|c| if c { '#' } else { '.' }).iter().collect())
+                .to_vec()))
+        } else {
+            Ok(read_letters(&image)?.into())
+        }
+    }
+}
+
+const EXAMPLE_INPUT: &str = "addx 15\naddx -11\naddx 6\naddx -3\naddx 5\naddx -1\naddx -8
+addx 13\naddx 4\nnoop\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1
+addx -35\naddx 1\naddx 24\naddx -19\naddx 1\naddx 16\naddx -11\nnoop\nnoop\naddx 21\naddx -15\nnoop
+noop\naddx -3\naddx 9\naddx 1\naddx -3\naddx 8\naddx 1\naddx 5\nnoop\nnoop\nnoop\nnoop\nnoop
+addx -36\nnoop\naddx 1\naddx 7\nnoop\nnoop\nnoop\naddx 2\naddx 6\nnoop\nnoop\nnoop\nnoop\nnoop
+addx 1\nnoop\nnoop\naddx 7\naddx 1\nnoop\naddx -13\naddx 13\naddx 7\nnoop\naddx 1\naddx -33\nnoop
+noop\nnoop\naddx 2\nnoop\nnoop\nnoop\naddx 8\nnoop\naddx -1\naddx 2\naddx 1\nnoop\naddx 17\naddx -9
+addx 1\naddx 1\naddx -3\naddx 11\nnoop\nnoop\naddx 1\nnoop\naddx 1\nnoop\nnoop\naddx -13\naddx -19
+addx 1\naddx 3\naddx 26\naddx -30\naddx 12\naddx -1\naddx 3\naddx 1\nnoop\nnoop\nnoop\naddx -9
+addx 18\naddx 1\naddx 2\nnoop\nnoop\naddx 9\nnoop\nnoop\nnoop\naddx -1\naddx 2\naddx -37\naddx 1
+addx 3\nnoop\naddx 15\naddx -21\naddx 22\naddx -6\naddx 1\nnoop\naddx 2\naddx 1\nnoop\naddx -10
+noop\nnoop\naddx 20\naddx 1\naddx 2\naddx 2\naddx -6\naddx -11\nnoop\nnoop\nnoop";
+
+const EXAMPLE_OUTPUT_2: &str = "
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day10::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_10);
+    }
+
+    #[test]
+    fn test_invalid_command() {
+        let error = DAY_10.solver.solve("noop\naddx 3\nsubx 2").unwrap_err();
+        assert_eq!("Invalid command", error.message);
+    }
+
+    #[test]
+    fn test_unrecognised_letters() {
+        let report = DAY_10.solver.solve(EXAMPLE_INPUT).unwrap();
+        assert_eq!(Ok(Answer::Integer(13140)), report.part1.unwrap().result);
+
+        let Err(error) = report.part2.unwrap().result else {
+            panic!("expected error");
+        };
+        assert!(error.message.starts_with("Unrecognised glyph at column 1:\n##..\n###.\n"), "{}", error.message);
+    }
+}
@@ -0,0 +1,221 @@
+use std::collections::VecDeque;
+
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, parse, SecondPart, SolveError, Solution, strip_prefix};
+use crate::years::year2022::day11::Operand::*;
+use crate::years::year2022::day11::OperationType::*;
+
+pub const DAY_11: Day = Day {
+    solver: DaySolver::new::<Day11>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("10605"), Some("2713310158")),
+    ],
+};
+
+#[derive(Clone)]
+enum Operand {
+    Old,
+    Value(usize),
+}
+
+impl TryFrom<&str> for Operand {
+    type Error = SolveError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        if s == "old" {
+            Ok(Old)
+        } else {
+            Ok(Value(parse(s)?))
+        }
+    }
+}
+
+#[derive(Clone)]
+enum OperationType {
+    Plus,
+    Multiply,
+}
+
+impl TryFrom<&str> for OperationType {
+    type Error = SolveError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "*" => Ok(Multiply),
+            "+" => Ok(Plus),
+            _ => Err(SolveError::at(s, "Unsupported operation type")),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Operation {
+    left: Operand,
+    operation: OperationType,
+    right: Operand,
+}
+
+impl TryFrom<&str> for Operation {
+    type Error = SolveError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let [left, operation, right] = s.split(' ').collect::<Vec<_>>()[..] else {
+            return Err(SolveError::at(s, "Invalid operation"));
+        };
+
+        Ok(Operation { left: left.try_into()?, operation: operation.try_into()?, right: right.try_into()? })
+    }
+}
+
+impl Operation {
+    fn execute(&self, old: usize) -> usize {
+        let left = match self.left {
+            Old => old,
+            Value(v) => v,
+        };
+        let right = match self.right {
+            Old => old,
+            Value(v) => v,
+        };
+        match self.operation {
+            Plus => left + right,
+            Multiply => left * right,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Monkey {
+    starting_items: VecDeque<usize>,
+    operation: Operation,
+    test_divisible_by: usize,
+    test_true_throws_to: usize,
+    test_false_throws_to: usize,
+}
+
+impl TryFrom<&str> for Monkey {
+    type Error = SolveError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let mut lines = s.lines().map(str::trim).skip(1);
+        let mut line = |prefix| strip_prefix(lines.next().unwrap_or_default(), prefix);
+
+        let starting_items = line("Starting items: ")?.split(", ")
+            .map(parse::<usize>)
+            .collect::<Result<VecDeque<_>, _>>()?;
+        let operation = line("Operation: new = ")?.try_into()?;
+        let test_divisible_by = parse(line("Test: divisible by ")?)?;
+        let test_true_throws_to = parse(line("If true: throw to monkey ")?)?;
+        let test_false_throws_to = parse(line("If false: throw to monkey ")?)?;
+
+        Ok(Self { starting_items, operation, test_divisible_by, test_true_throws_to, test_false_throws_to })
+    }
+}
+
+struct Day11;
+
+impl Solution for Day11 {
+    type Parsed<'a> = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        let monkeys = input.split("\n\n").map(Monkey::try_from).collect::<Result<Vec<_>, _>>()?;
+        if monkeys.iter().any(|m| m.test_true_throws_to >= monkeys.len() || m.test_false_throws_to >= monkeys.len()) {
+            return Err(SolveError::new("Monkey throws to a monkey which does not exist"));
+        }
+        // Items thrown back into the list being emptied would keep the monkey busy forever.
+        if monkeys.iter().enumerate().any(|(i, m)| m.test_true_throws_to == i || m.test_false_throws_to == i) {
+            return Err(SolveError::new("Monkey throws to itself"));
+        }
+        if monkeys.iter().any(|m| m.test_divisible_by == 0) {
+            return Err(SolveError::new("Monkey tests for divisibility by zero"));
+        }
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(process_rounds(monkeys.clone(), 20, true).into())
+    }
+}
+
+impl SecondPart for Day11 {
+    fn part2(monkeys: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(process_rounds(monkeys.clone(), 10000, false).into())
+    }
+}
+
+fn process_rounds(mut monkeys: Vec<Monkey>, rounds: usize, with_worry_reduction: bool) -> usize {
+    let crt_mod = monkeys.iter().map(|m| m.test_divisible_by).product::<usize>();
+    let mut inspection_count = vec![0usize; monkeys.len()];
+
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            while let Some(mut worry) = monkeys[i].starting_items.pop_front() {
+                inspection_count[i] += 1;
+                worry = monkeys[i].operation.execute(worry);
+                if with_worry_reduction {
+                    worry /= 3;
+                }
+                worry %= crt_mod;
+                let throw_to = if worry % monkeys[i].test_divisible_by == 0 {
+                    monkeys[i].test_true_throws_to
+                } else {
+                    monkeys[i].test_false_throws_to
+                };
+                monkeys[throw_to].starting_items.push_back(worry);
+            }
+        }
+    }
+
+    inspection_count.sort();
+    inspection_count.into_iter().rev().take(2).product::<usize>()
+}
+
+const EXAMPLE_INPUT: &str = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day11::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_11);
+    }
+
+    #[test]
+    fn test_throw_to_itself() {
+        let input = EXAMPLE_INPUT.replacen("If true: throw to monkey 2", "If true: throw to monkey 0", 1);
+        let Err(error) = DAY_11.solver.solve(&input) else {
+            panic!("expected error");
+        };
+
+        assert_eq!("Monkey throws to itself", error.message);
+    }
+}
\ No newline at end of file
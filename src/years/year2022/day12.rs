@@ -0,0 +1,174 @@
+use std::collections::VecDeque;
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, Rendering, SecondPart, SolveError, Solution};
+use crate::render::{Canvas, Color, Pixel, Render};
+
+pub const DAY_12: Day = Day {
+    solver: DaySolver::new::<Day12>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("31"), Some("29")),
+    ],
+};
+
+struct Heightmap {
+    map: Vec<Vec<u8>>,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+impl Heightmap {
+    // The number of steps from each position to the best signal, found by walking backwards from it.
+    fn distances_to_end(&self) -> Vec<Vec<Option<usize>>> {
+        let map = &self.map;
+        let (height, width) = (map.len(), map[0].len());
+        let mut distances: Vec<Vec<Option<usize>>> = vec![vec![None; width]; height];
+        let mut queue = VecDeque::from([(self.end.0, self.end.1, 0)]);
+        while let Some((r, c, d)) = queue.pop_front() {
+            if distances[r][c].is_some() {
+                continue;
+            }
+            distances[r][c] = Some(d);
+
+            if r > 0 && map[r - 1][c] >= map[r][c] - 1 {
+                queue.push_back((r - 1, c, d + 1));
+            }
+
+            if r < height - 1 && map[r + 1][c] >= map[r][c] - 1 {
+                queue.push_back((r + 1, c, d + 1));
+            }
+
+            if c > 0 && map[r][c - 1] >= map[r][c] - 1 {
+                queue.push_back((r, c - 1, d + 1));
+            }
+
+            if c < width - 1 && map[r][c + 1] >= map[r][c] - 1 {
+                queue.push_back((r, c + 1, d + 1));
+            }
+        }
+        distances
+    }
+}
+
+struct Day12;
+
+impl Solution for Day12 {
+    type Parsed<'a> = Heightmap;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        if let Some(invalid) = input.find(|c: char| !(c.is_ascii_lowercase() || c == 'S' || c == 'E' || c == '\n')) {
+            return Err(SolveError::at(&input[invalid..], "Invalid elevation"));
+        }
+
+        let mut map = input.lines()
+            .map(|l| l.as_bytes().to_vec())
+            .collect::<Vec<_>>();
+        let width = map.first().map_or(0, Vec::len);
+        if width == 0 || map.iter().any(|r| r.len() != width) {
+            return Err(SolveError::new("Map is not a non-empty rectangle"));
+        }
+
+        let mut start = None;
+        let mut end = None;
+        for (r, row) in map.iter_mut().enumerate() {
+            for (c, elevation) in row.iter_mut().enumerate() {
+                if *elevation == b'S' {
+                    start = Some((r, c));
+                    *elevation = b'a';
+                } else if *elevation == b'E' {
+                    end = Some((r, c));
+                    *elevation = b'z';
+                }
+            }
+        }
+        let start = start.ok_or_else(|| SolveError::new("No start position"))?;
+        let end = end.ok_or_else(|| SolveError::new("No best signal position"))?;
+
+        Ok(Heightmap { map, start, end })
+    }
+
+    fn part1(heightmap: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        let (r, c) = heightmap.start;
+        Ok(heightmap.distances_to_end()[r][c].ok_or_else(|| SolveError::new("No path from the start"))?.into())
+    }
+
+    fn render(heightmap: &Self::Parsed<'_>) -> Result<Rendering, SolveError> {
+        Ok(Some(Box::new(Distances {
+            map: heightmap.map.clone(),
+            distances: heightmap.distances_to_end(),
+            start: heightmap.start,
+            end: heightmap.end,
+        })))
+    }
+}
+
+impl SecondPart for Day12 {
+    fn part2(heightmap: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        let distances = heightmap.distances_to_end();
+        Ok(heightmap.map.iter().flatten()
+            .zip(distances.iter().flatten())
+            .filter(|&(&elevation, _)| elevation == b'a')
+            .filter_map(|(_, &distance)| distance)
+            .min().ok_or_else(|| SolveError::new("No path from any lowest point"))?.into())
+    }
+}
+
+// Squares shaded from near to far from the best signal, with those that cannot reach it left dark.
+struct Distances {
+    map: Vec<Vec<u8>>,
+    distances: Vec<Vec<Option<usize>>>,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+impl Render for Distances {
+    fn size(&self) -> (usize, usize) {
+        (self.map[0].len(), self.map.len())
+    }
+
+    fn render(&self, canvas: &mut dyn Canvas) {
+        let furthest = self.distances.iter().flatten().flatten().max().copied().unwrap_or(0).max(1);
+        for (r, (row, distances)) in self.map.iter().zip(&self.distances).enumerate() {
+            for (c, (&elevation, &distance)) in row.iter().zip(distances).enumerate() {
+                let pixel = if (r, c) == self.start {
+                    Pixel::new('S', Color(230, 40, 40))
+                } else if (r, c) == self.end {
+                    Pixel::new('E', Color(230, 40, 40))
+                } else {
+                    let color = distance.map_or(Color(30, 30, 30), |d| Color(250, 220, 60).mix(Color(40, 40, 160), d as f64 / furthest as f64));
+                    Pixel::new(elevation as char, color)
+                };
+                canvas.draw(c, r, pixel);
+            }
+        }
+    }
+}
+
+const EXAMPLE_INPUT: &str = "\
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::{assert_examples, Parameters};
+    use crate::render::Backend;
+    use crate::years::year2022::day12::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_12);
+    }
+
+    #[test]
+    fn test_render() {
+        let drawing = DAY_12.solver.render(EXAMPLE_INPUT, &Parameters::default()).unwrap().unwrap();
+        let text = String::from_utf8(Backend::Ascii.render(drawing.as_ref())).unwrap();
+
+        // The start and end are drawn over the elevations they stand for.
+        assert_eq!(Some("Sabqponm"), text.lines().next());
+        assert_eq!(Some("accszExk"), text.lines().nth(2));
+        assert_eq!(format!("{}\n", EXAMPLE_INPUT), text);
+    }
+}
\ No newline at end of file
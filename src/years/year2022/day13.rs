@@ -0,0 +1,145 @@
+use std::cmp::Ordering;
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, SecondPart, SolveError, Solution};
+use crate::years::year2022::day13::Value::{Integer, List};
+
+pub const DAY_13: Day = Day {
+    solver: DaySolver::new::<Day13>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("13"), Some("140")),
+    ],
+};
+
+#[derive(Clone, Eq, PartialEq)]
+enum Value { Integer(usize), List(Vec<Value>) }
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Integer(a), Integer(b)) => a.cmp(b),
+            (&Integer(i), l @ List(_)) => List(vec![Integer(i)]).cmp(l),
+            (l @ List(_), &Integer(i)) => l.cmp(&List(vec![Integer(i)])),
+            (List(l1), List(l2)) => l1.iter()
+                .zip(l2)
+                .map(|(l, r)| l.cmp(r))
+                .find(|r| r != &Ordering::Equal)
+                .unwrap_or(l1.len().cmp(&l2.len())),
+        }
+    }
+}
+
+impl TryFrom<&str> for Value {
+    type Error = SolveError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let mut stack = vec![];
+        let mut current_integer = None;
+        for (i, c) in s.bytes().enumerate() {
+            if c == b'[' {
+                stack.push(List(vec![]));
+            } else if c.is_ascii_digit() {
+                current_integer = Some(current_integer.unwrap_or(0) * 10 + (c - b'0') as usize);
+            } else if c != b',' && c != b']' {
+                return Err(SolveError::at(&s[i..], "Invalid packet"));
+            }
+
+            if current_integer.is_some() && (c == b',' || c == b']') {
+                let Some(List(list)) = stack.last_mut() else {
+                    return Err(SolveError::at(&s[i..], "Integer outside of a list"));
+                };
+                list.push(Integer(current_integer.unwrap()));
+                current_integer = None;
+            }
+
+            if c == b']' {
+                let done = stack.pop().ok_or_else(|| SolveError::at(&s[i..], "List closed without being opened"))?;
+                let Some(List(list)) = stack.last_mut() else {
+                    return if i + 1 == s.len() {
+                        Ok(done)
+                    } else {
+                        Err(SolveError::at(&s[i + 1..], "Trailing characters after packet"))
+                    };
+                };
+                list.push(done);
+            }
+        }
+
+        Err(SolveError::at(s, "Outer list was never closed"))
+    }
+}
+
+struct Day13;
+
+impl Solution for Day13 {
+    type Parsed<'a> = Vec<Value>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        input.lines()
+            .filter(|l| !l.is_empty())
+            .map(Value::try_from)
+            .collect()
+    }
+
+    fn part1(values: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(values.chunks_exact(2).enumerate()
+            .filter(|(_, l)| l[0] < l[1])
+            .map(|(i, _)| i + 1)
+            .sum::<usize>().into())
+    }
+}
+
+impl SecondPart for Day13 {
+    fn part2(values: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        let marker1 = List(vec![List(vec![Integer(2)])]);
+        let marker2 = List(vec![List(vec![Integer(6)])]);
+        let mut values = values.clone();
+        values.push(marker1.clone());
+        values.push(marker2.clone());
+        values.sort();
+        let index1 = values.binary_search(&marker1).unwrap() + 1;
+        let index2 = values.binary_search(&marker2).unwrap() + 1;
+        Ok((index1 * index2).into())
+    }
+}
+
+const EXAMPLE_INPUT: &str = "\
+[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day13::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_13);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,243 @@
+use std::cmp::{max, min};
+use std::collections::HashSet;
+
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, Parameters, parse, Rendering, SecondPart, SolveError, Solution, split_once};
+use crate::render::{Canvas, Color, Pixel, Render};
+
+pub const DAY_14: Day = Day {
+    solver: DaySolver::new::<Day14>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("24"), Some("93")),
+        Example::new(EXAMPLE_INPUT_SHIFTED, Some("24"), Some("93")).with_parameters(&[("source_x", -100), ("source_y", -3)]),
+    ],
+};
+
+const SOURCE: Coordinate = Coordinate { x: 500, y: 0 };
+
+// Wide enough that moving sand one step further in any direction never overflows.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+struct Coordinate {
+    x: i64,
+    y: i64,
+}
+
+impl TryFrom<&str> for Coordinate {
+    type Error = SolveError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let (x, y) = split_once(s, ",")?;
+        Ok(Self { x: parse::<i32>(x)?.into(), y: parse::<i32>(y)?.into() })
+    }
+}
+
+struct Path {
+    path: Vec<Coordinate>,
+}
+
+impl TryFrom<&str> for Path {
+    type Error = SolveError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let path = s.split(" -> ").map(Coordinate::try_from).collect::<Result<Vec<_>, _>>()?;
+        if path.windows(2).any(|w| w[0].x != w[1].x && w[0].y != w[1].y) {
+            return Err(SolveError::at(s, "Diagonal rock path"));
+        }
+        Ok(Self { path })
+    }
+}
+
+impl Path {
+    fn rocks(&self) -> impl Iterator<Item=Coordinate> + '_ {
+        self.path.iter().zip(self.path.iter().skip(1)).flat_map(|(from, to)| {
+            (min(from.x, to.x)..=max(from.x, to.x))
+                .flat_map(move |x| (min(from.y, to.y)..=max(from.y, to.y)).map(move |y| Coordinate { x, y }))
+        }).chain(self.path.first().copied())
+    }
+}
+
+// Only the blocked cells are stored, so the cave has no edges for sand to flow past.
+struct Cave {
+    rocks: HashSet<Coordinate>,
+    // The lowest rock, below which sand falls forever unless it lands on the floor.
+    bottom: i64,
+    source: Coordinate,
+}
+
+impl Cave {
+    fn pour(&self, has_floor: bool) -> usize {
+        self.settle(has_floor).len()
+    }
+
+    // Finds where the units of sand come to rest, until one falls into the abyss or the source is blocked.
+    fn settle(&self, has_floor: bool) -> HashSet<Coordinate> {
+        let floor = self.bottom + 2;
+        let mut blocked = self.rocks.clone();
+        let mut sand = HashSet::new();
+        if blocked.contains(&self.source) {
+            return sand;
+        }
+
+        // The route of the previous unit, as the next one follows it until its last free position.
+        let mut route = vec![self.source];
+        while let Some(&Coordinate { x, y }) = route.last() {
+            if !has_floor && y >= self.bottom {
+                break;
+            }
+
+            let next = [x, x - 1, x + 1].into_iter()
+                .map(|x| Coordinate { x, y: y + 1 })
+                .find(|c| c.y != floor && !blocked.contains(c));
+            match next {
+                Some(next) => route.push(next),
+                None => {
+                    blocked.insert(Coordinate { x, y });
+                    sand.insert(Coordinate { x, y });
+                    route.pop();
+                }
+            }
+        }
+        sand
+    }
+}
+
+fn parse_cave(input: &str, source: Coordinate) -> Result<Cave, SolveError> {
+    let paths = input.lines()
+        .map(Path::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+    let rocks = paths.iter().flat_map(Path::rocks).collect::<HashSet<_>>();
+    let bottom = rocks.iter().map(|c| c.y).max().ok_or_else(|| SolveError::new("No rock paths"))?;
+    if source.y >= bottom + 2 {
+        return Err(SolveError::new("Sand source is not above the floor"));
+    }
+    Ok(Cave { rocks, bottom, source })
+}
+
+fn source_coordinate(parameters: &Parameters, name: &str, default: i64) -> Result<i64, SolveError> {
+    let value = parameters.get(name).unwrap_or(default);
+    i32::try_from(value).map(i64::from).map_err(|_| SolveError::new(format!("Parameter {} is out of range", name)))
+}
+
+struct Day14;
+
+impl Solution for Day14 {
+    type Parsed<'a> = Cave;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Self::parse_with(input, &Parameters::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, parameters: &Parameters) -> Result<Self::Parsed<'a>, SolveError> {
+        let source = Coordinate {
+            x: source_coordinate(parameters, "source_x", SOURCE.x)?,
+            y: source_coordinate(parameters, "source_y", SOURCE.y)?,
+        };
+        parse_cave(input, source)
+    }
+
+    fn part1(cave: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(cave.pour(false).into())
+    }
+
+    fn render(cave: &Self::Parsed<'_>) -> Result<Rendering, SolveError> {
+        let sand = cave.settle(true);
+        let cells = || cave.rocks.iter().chain(&sand).chain([&cave.source]);
+        let min = Coordinate { x: cells().map(|c| c.x).min().unwrap_or(0) - 1, y: cells().map(|c| c.y).min().unwrap_or(0) };
+        let max = Coordinate { x: cells().map(|c| c.x).max().unwrap_or(0) + 1, y: cave.bottom + 2 };
+        Ok(Some(Box::new(SandPile { rocks: cave.rocks.clone(), sand, source: cave.source, min, max })))
+    }
+}
+
+impl SecondPart for Day14 {
+    fn part2(cave: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(cave.pour(true).into())
+    }
+}
+
+// The cave once the source is blocked, including the floor.
+struct SandPile {
+    rocks: HashSet<Coordinate>,
+    sand: HashSet<Coordinate>,
+    source: Coordinate,
+    min: Coordinate,
+    max: Coordinate,
+}
+
+impl Render for SandPile {
+    fn size(&self) -> (usize, usize) {
+        ((self.max.x - self.min.x + 1) as usize, (self.max.y - self.min.y + 1) as usize)
+    }
+
+    fn render(&self, canvas: &mut dyn Canvas) {
+        for y in self.min.y..=self.max.y {
+            for x in self.min.x..=self.max.x {
+                let coordinate = Coordinate { x, y };
+                let pixel = if self.rocks.contains(&coordinate) || y == self.max.y {
+                    Pixel::new('#', Color(120, 120, 130))
+                } else if coordinate == self.source && !self.sand.contains(&coordinate) {
+                    Pixel::new('+', Color(230, 40, 40))
+                } else if self.sand.contains(&coordinate) {
+                    Pixel::new('o', Color(230, 200, 110))
+                } else {
+                    Pixel::new('.', Color(20, 20, 30))
+                };
+                canvas.draw((x - self.min.x) as usize, (y - self.min.y) as usize, pixel);
+            }
+        }
+    }
+}
+
+const EXAMPLE_INPUT: &str = "\
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+// The example moved into negative coordinates, with the source moved along.
+const EXAMPLE_INPUT_SHIFTED: &str = "\
+-102,1 -> -102,3 -> -104,3
+-97,1 -> -98,1 -> -98,6 -> -106,6";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::render::Backend;
+    use crate::years::year2022::day14::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_14);
+    }
+
+    #[test]
+    fn test_render() {
+        let drawing = DAY_14.solver.render(EXAMPLE_INPUT, &Parameters::default()).unwrap().unwrap();
+        let text = String::from_utf8(Backend::Ascii.render(drawing.as_ref())).unwrap();
+
+        assert_eq!(Some("...........o..........."), text.lines().next());
+        assert_eq!(Some("..ooo#########ooooooo.."), text.lines().nth(9));
+        assert_eq!(Some("#######################"), text.lines().last());
+    }
+
+    #[test]
+    fn test_wide_pyramid() {
+        // Sand piles up far beyond the rocks on both sides before reaching the source.
+        let cave = parse_cave("500,30 -> 500,30", SOURCE).unwrap();
+        assert_eq!(0, cave.pour(false));
+        assert_eq!(32 * 32 - 1, cave.pour(true));
+    }
+
+    #[test]
+    fn test_blocked_source() {
+        let cave = parse_cave("499,0 -> 501,0", SOURCE).unwrap();
+        assert_eq!(0, cave.pour(false));
+        assert_eq!(0, cave.pour(true));
+    }
+
+    #[test]
+    fn test_source_below_floor() {
+        let Err(error) = parse_cave("500,2 -> 501,2", Coordinate { x: 500, y: 4 }) else {
+            panic!("expected error");
+        };
+        assert_eq!("Sand source is not above the floor", error.message);
+        assert!(DAY_14.solver.solve_with("1,1 -> 1,2", &Parameters::from_iter([("source_x", i64::MAX)])).is_err());
+    }
+}
\ No newline at end of file
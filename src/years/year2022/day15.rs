@@ -1,16 +1,24 @@
-use crate::day::DaySolver;
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, Parameters, parse, SecondPart, SolveError, Solution, split_once, strip_prefix};
 
-pub const DAY_15: DaySolver = DaySolver::Standard { part1: Some(part1), part2: Some(part2) };
+pub const DAY_15: Day = Day {
+    solver: DaySolver::new::<Day15>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("26"), Some("56000011")).with_parameters(&[("row", 10), ("window", 20)]),
+    ],
+};
 
 struct Coordinate {
     x: i32,
     y: i32,
 }
 
-impl From<&str> for Coordinate {
-    fn from(s: &str) -> Self {
-        let (x, y) = s.split_once(", ").unwrap();
-        Self { x: x[2..].parse().unwrap(), y: y[2..].parse().unwrap() }
+impl TryFrom<&str> for Coordinate {
+    type Error = SolveError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let (x, y) = split_once(s, ", ")?;
+        Ok(Self { x: parse(strip_prefix(x, "x=")?)?, y: parse(strip_prefix(y, "y=")?)? })
     }
 }
 
@@ -20,16 +28,22 @@ struct Sensor {
     distance: i32,
 }
 
-impl From<&str> for Sensor {
-    fn from(s: &str) -> Self {
-        let (sensor, beacon) = s[10..].split_once(": closest beacon is at ").unwrap();
-        let position: Coordinate = sensor.into();
-        let closest_beacon: Coordinate = beacon.into();
+impl TryFrom<&str> for Sensor {
+    type Error = SolveError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let (sensor, beacon) = split_once(strip_prefix(s, "Sensor at ")?, ": closest beacon is at ")?;
+        let position: Coordinate = sensor.try_into()?;
+        let closest_beacon: Coordinate = beacon.try_into()?;
         let distance_to_beacon = (position.x - closest_beacon.x).abs() + (position.y - closest_beacon.y).abs();
-        Self { position, closest_beacon, distance: distance_to_beacon }
+        Ok(Self { position, closest_beacon, distance: distance_to_beacon })
     }
 }
 
+fn parse_sensors(input: &str) -> Result<Vec<Sensor>, SolveError> {
+    input.lines().map(Sensor::try_from).collect()
+}
+
 fn split_range(range: (i32, i32), at: i32) -> ((i32, i32), Option<(i32, i32)>) {
     if range.0 == range.1 {
         panic!("cannot split 1-width range");
@@ -47,7 +61,10 @@ fn split_range(range: (i32, i32), at: i32) -> ((i32, i32), Option<(i32, i32)>) {
 fn merge_ranges(mut ranges: Vec<(i32, i32)>) -> Vec<(i32, i32)> {
     ranges.sort_by(|l, r| l.0.cmp(&r.0).then(l.1.cmp(&r.1)));
     let mut non_overlapped_ranges = vec![];
-    let mut current = ranges[0];
+    let Some(&first) = ranges.first() else {
+        return non_overlapped_ranges;
+    };
+    let mut current = first;
     for range in ranges.into_iter().skip(1) {
         if range.0 <= current.1 + 1 {
             if range.1 > current.1 {
@@ -62,12 +79,46 @@ fn merge_ranges(mut ranges: Vec<(i32, i32)>) -> Vec<(i32, i32)> {
     non_overlapped_ranges
 }
 
-fn part1(input: &str) -> String {
-    part1_internal(input, 2000000)
+struct Scan {
+    sensors: Vec<Sensor>,
+    row: i32,
+    window: i32,
 }
 
-fn part1_internal(input: &str, y: i32) -> String {
-    let sensors = input.lines().map(Sensor::from).collect::<Vec<_>>();
+fn parameter(parameters: &Parameters, name: &str, default: i32) -> Result<i32, SolveError> {
+    parameters.get(name).map_or(Ok(default), |value| i32::try_from(value)
+        .map_err(|_| SolveError::new(format!("Parameter {} is out of range", name))))
+}
+
+struct Day15;
+
+impl Solution for Day15 {
+    type Parsed<'a> = Scan;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Self::parse_with(input, &Parameters::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, parameters: &Parameters) -> Result<Self::Parsed<'a>, SolveError> {
+        Ok(Scan {
+            sensors: parse_sensors(input)?,
+            row: parameter(parameters, "row", 2000000)?,
+            window: parameter(parameters, "window", 4000000)?,
+        })
+    }
+
+    fn part1(scan: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        part1_internal(&scan.sensors, scan.row)
+    }
+}
+
+impl SecondPart for Day15 {
+    fn part2(scan: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        part2_internal(&scan.sensors, scan.window)
+    }
+}
+
+fn part1_internal(sensors: &[Sensor], y: i32) -> Result<Answer, SolveError> {
     let mut ranges = vec![];
     for sensor in sensors {
         let perp_distance = (sensor.position.y - y).abs();
@@ -100,22 +151,16 @@ fn part1_internal(input: &str, y: i32) -> String {
         }
     }
 
-    merge_ranges(ranges).into_iter()
+    Ok(merge_ranges(ranges).into_iter()
         .map(|r| r.1 - r.0 + 1)
-        .sum::<i32>().to_string()
-}
-
-fn part2(input: &str) -> String {
-    part2_internal(input, 4000000)
+        .sum::<i32>().into())
 }
 
 fn has_gap_in_window(sorted_non_overlapping: &[(i32, i32)], window: i32) -> Option<i32> {
-    let start_index = sorted_non_overlapping.iter().enumerate()
-        .filter(|(_, r)| r.1 > 0)
-        .next().unwrap().0;
-    let end_index = sorted_non_overlapping.iter().enumerate().rev()
-        .filter(|(_, r)| r.0 <= window)
-        .next().unwrap().0;
+    let start_index = sorted_non_overlapping.iter()
+        .position(|r| r.1 > 0)?;
+    let end_index = sorted_non_overlapping.iter()
+        .rposition(|r| r.0 <= window)?;
 
     if start_index == end_index {
         None
@@ -124,11 +169,10 @@ fn has_gap_in_window(sorted_non_overlapping: &[(i32, i32)], window: i32) -> Opti
     }
 }
 
-fn part2_internal(input: &str, window: i32) -> String {
-    let sensors = input.lines().map(Sensor::from).collect::<Vec<_>>();
+fn part2_internal(sensors: &[Sensor], window: i32) -> Result<Answer, SolveError> {
     for y in 0..=window {
         let mut ranges = vec![];
-        for sensor in &sensors {
+        for sensor in sensors {
             let perp_distance = (sensor.position.y - y).abs();
             if perp_distance > sensor.distance {
                 continue;
@@ -142,17 +186,13 @@ fn part2_internal(input: &str, window: i32) -> String {
 
         let non_overlapping_occupied_ranges = merge_ranges(ranges);
         if let Some(x) = has_gap_in_window(&non_overlapping_occupied_ranges, window) {
-            return (x as usize * 4000000 + y as usize).to_string();
+            return Ok((x as usize * 4000000 + y as usize).into());
         }
     }
-    panic!("no solution");
+    Err(SolveError::new("No solution"))
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::days::day15::*;
-
-    const EXAMPLE_INPUT: &str = "\
+const EXAMPLE_INPUT: &str = "\
 Sensor at x=2, y=18: closest beacon is at x=-2, y=15
 Sensor at x=9, y=16: closest beacon is at x=10, y=16
 Sensor at x=13, y=2: closest beacon is at x=15, y=3
@@ -168,23 +208,32 @@ Sensor at x=16, y=7: closest beacon is at x=15, y=3
 Sensor at x=14, y=3: closest beacon is at x=15, y=3
 Sensor at x=20, y=1: closest beacon is at x=15, y=3";
 
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day15::*;
+
     #[test]
-    fn test_part1() {
-        assert_eq!("26", part1_internal(EXAMPLE_INPUT, 10));
+    fn test_examples() {
+        assert_examples(&DAY_15);
     }
 
     #[test]
     fn test_part1_1() {
-        assert_eq!("25", part1_internal(EXAMPLE_INPUT, 9));
+        assert_eq!(Answer::Integer(25), part1_internal(&parse_sensors(EXAMPLE_INPUT).unwrap(), 9).unwrap());
     }
 
     #[test]
     fn test_part1_2() {
-        assert_eq!("27", part1_internal(EXAMPLE_INPUT, 11));
+        assert_eq!(Answer::Integer(27), part1_internal(&parse_sensors(EXAMPLE_INPUT).unwrap(), 11).unwrap());
     }
 
     #[test]
-    fn test_part2() {
-        assert_eq!("56000011", part2_internal(EXAMPLE_INPUT, 20));
+    fn test_parameter_out_of_range() {
+        let Err(error) = DAY_15.solver.solve_with(EXAMPLE_INPUT, &Parameters::from_iter([("row", 1 << 32)])) else {
+            panic!("expected error");
+        };
+
+        assert_eq!("Parameter row is out of range", error.message);
     }
 }
\ No newline at end of file
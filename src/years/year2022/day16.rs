@@ -1,9 +1,15 @@
 use std::cmp::max;
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
-use crate::day::DaySolver;
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, parse, SecondPart, SolveError, Solution, split_once, strip_prefix};
 
-pub const DAY_16: DaySolver = DaySolver::Standard { part1: Some(part1), part2: Some(part2) };
+pub const DAY_16: Day = Day {
+    solver: DaySolver::new::<Day16>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("1651"), Some("1707")),
+    ],
+};
 
 #[derive(Debug)]
 struct Valve {
@@ -18,7 +24,7 @@ struct Map {
 }
 
 impl FromStr for Map {
-    type Err = ();
+    type Err = SolveError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut name_to_index = HashMap::new();
@@ -26,29 +32,28 @@ impl FromStr for Map {
         let raw_valves = s.lines()
             .enumerate()
             .map(|(i, l)| {
-                let name = l[6..8].to_owned();
-                name_to_index.insert(name.to_owned(), i);
-                let semicolon_index = l.find(';').unwrap();
-                let flow_rate = l[23..semicolon_index].parse::<i32>().unwrap();
-
-                let valves_index = if l.contains("tunnels") {
-                    semicolon_index + 25
-                } else {
-                    semicolon_index + 24
-                };
-
-                let tunnels = l[valves_index..].split(", ").collect::<Vec<_>>();
-                (flow_rate, tunnels)
+                let (name, rest) = split_once(strip_prefix(l, "Valve ")?, " has flow rate=")?;
+                name_to_index.insert(name, i);
+                let (flow_rate, tunnels) = split_once(rest, "; ")?;
+                let flow_rate = parse::<i32>(flow_rate)?;
+
+                let tunnels = tunnels.strip_prefix("tunnels lead to valves ")
+                    .or_else(|| tunnels.strip_prefix("tunnel leads to valve "))
+                    .ok_or_else(|| SolveError::at(tunnels, "Expected tunnels"))?
+                    .split(", ").collect::<Vec<_>>();
+                Ok((flow_rate, tunnels))
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, SolveError>>()?;
 
         let mut valves = raw_valves.iter()
             .map(|(flow_rate, _)| Valve { tunnels: vec![], flow_rate: *flow_rate })
             .collect::<Vec<_>>();
 
         for (i, (_, raw_tunnels)) in raw_valves.into_iter().enumerate() {
-            let mut tunnel_indexes = raw_tunnels.into_iter().map(|t| name_to_index[t]).collect();
-            valves[i].tunnels.append(&mut tunnel_indexes);
+            for tunnel in raw_tunnels {
+                let index = name_to_index.get(tunnel).ok_or_else(|| SolveError::at(tunnel, "Unknown valve"))?;
+                valves[i].tunnels.push(*index);
+            }
         }
 
         let mut distances = vec![vec![i32::MAX; valves.len()]; valves.len()];
@@ -72,7 +77,8 @@ impl FromStr for Map {
             }
         }
 
-        Ok(Map { valves, start: name_to_index["AA"], distances })
+        let start = *name_to_index.get("AA").ok_or_else(|| SolveError::new("No valve AA"))?;
+        Ok(Map { valves, start, distances })
     }
 }
 
@@ -107,18 +113,12 @@ impl Person {
 
     #[inline]
     fn is_awaiting(&self) -> bool {
-        match self {
-            Person::Awaiting { .. } => true,
-            _ => false,
-        }
+        matches!(self, Person::Awaiting { .. })
     }
 
     #[inline]
     fn is_idle(&self) -> bool {
-        match self {
-            Person::Idle => true,
-            _ => false,
-        }
+        matches!(self, Person::Idle)
     }
 
     #[inline]
@@ -156,7 +156,7 @@ fn permute_people<const N: usize>(map: &Map, mut people: [Person; N], person_ind
         return max_pressure(map, people, time_remaining, shut_valves, release_per_minute, current_released);
     };
 
-    let next_person = people.iter().enumerate().skip(person_index + 1).filter(|(_, p)| p.is_awaiting()).map(|(i, _)| i).next();
+    let next_person = people.iter().enumerate().skip(person_index + 1).find(|(_, p)| p.is_awaiting()).map(|(i, _)| i);
     let current_position = people[person_index].awaiting_in_position().unwrap();
 
     let considerable_valves = shut_valves.iter()
@@ -172,7 +172,7 @@ fn permute_people<const N: usize>(map: &Map, mut people: [Person; N], person_ind
     let mut max_pressure_value = 0;
 
     for target_valve in considerable_valves {
-        let mut new_people = people.clone();
+        let mut new_people = people;
 
         new_people[person_index] = Person::Moving {
             position: target_valve,
@@ -210,32 +210,37 @@ fn max_pressure<const N: usize>(map: &Map, mut people: [Person; N], mut time_rem
         *p = Person::Awaiting { position };
     });
 
-    let first_person_index = people.iter().enumerate().filter(|(_, p)| p.is_awaiting()).next().unwrap().0;
+    let first_person_index = people.iter().position(|p| p.is_awaiting()).unwrap();
     permute_people(map, people, Some(first_person_index), time_remaining, shut_valves, release_per_minute, current_released)
 }
 
-fn find_max_pressure<const N: usize>(input: &str, time: i32) -> i32 {
-    let map: Map = input.parse().unwrap();
+fn find_max_pressure<const N: usize>(map: &Map, time: i32) -> i32 {
     let mut shut_valves = map.valves.iter().enumerate().filter(|(_, v)| v.flow_rate > 0).map(|(i, _)| i).collect();
-    max_pressure(&map, [Person::new(map.start); N], time, &mut shut_valves, 0, 0)
+    max_pressure(map, [Person::new(map.start); N], time, &mut shut_valves, 0, 0)
 }
 
-fn part1(input: &str) -> String {
-    find_max_pressure::<1>(input, 30).to_string()
-}
+struct Day16;
+
+impl Solution for Day16 {
+    type Parsed<'a> = Map;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        input.parse()
+    }
 
-fn part2(input: &str) -> String {
-    find_max_pressure::<2>(input, 26).to_string()
+    fn part1(map: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(find_max_pressure::<1>(map, 30).into())
+    }
 }
 
+impl SecondPart for Day16 {
+    fn part2(map: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(find_max_pressure::<2>(map, 26).into())
+    }
+}
 
-#[cfg(test)]
-mod tests {
-    use crate::days::day16::*;
 
-    #[test]
-    fn test1() {
-        let input = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+const EXAMPLE_INPUT: &str = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
 Valve BB has flow rate=13; tunnels lead to valves CC, AA
 Valve CC has flow rate=2; tunnels lead to valves DD, BB
 Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
@@ -246,22 +251,13 @@ Valve HH has flow rate=22; tunnel leads to valve GG
 Valve II has flow rate=0; tunnels lead to valves AA, JJ
 Valve JJ has flow rate=21; tunnel leads to valve II";
 
-        assert_eq!("1651", part1(input));
-    }
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day16::*;
 
     #[test]
-    fn test2() {
-        let input = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
-Valve BB has flow rate=13; tunnels lead to valves CC, AA
-Valve CC has flow rate=2; tunnels lead to valves DD, BB
-Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
-Valve EE has flow rate=3; tunnels lead to valves FF, DD
-Valve FF has flow rate=0; tunnels lead to valves EE, GG
-Valve GG has flow rate=0; tunnels lead to valves FF, HH
-Valve HH has flow rate=22; tunnel leads to valve GG
-Valve II has flow rate=0; tunnels lead to valves AA, JJ
-Valve JJ has flow rate=21; tunnel leads to valve II";
-
-        assert_eq!("1707", part2(input));
+    fn test_examples() {
+        assert_examples(&DAY_16);
     }
-}
\ No newline at end of file
+}
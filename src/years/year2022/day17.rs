@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, SecondPart, SolveError, Solution};
+
+pub const DAY_17: Day = Day {
+    solver: DaySolver::new::<Day17>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("3068"), Some("1514285714288")),
+    ],
+};
+
+// Rows are bitmasks from the bottom up where bit 6 is the leftmost column of the chamber.
+// Each rock starts out with its left edge two units away from the left wall.
+const ROCKS: [&[u8]; 5] = [
+    &[0b0011110],
+    &[0b0001000, 0b0011100, 0b0001000],
+    &[0b0011100, 0b0000100, 0b0000100],
+    &[0b0010000, 0b0010000, 0b0010000, 0b0010000],
+    &[0b0011000, 0b0011000],
+];
+
+const WIDTH: usize = 7;
+const LEFT_WALL: u8 = 1 << (WIDTH - 1);
+const RIGHT_WALL: u8 = 1;
+
+// Columns whose topmost rock is further down than this all look the same to the rocks still falling,
+// which keeps a column that rocks never reach from making every state unique.
+const MAX_DEPTH: usize = 64;
+
+#[derive(Copy, Clone)]
+enum Jet {
+    Left,
+    Right,
+}
+
+fn parse_jets(input: &str) -> Result<Vec<Jet>, SolveError> {
+    let input = input.trim();
+    let jets = input.char_indices()
+        .map(|(i, c)| match c {
+            '<' => Ok(Jet::Left),
+            '>' => Ok(Jet::Right),
+            _ => Err(SolveError::at(&input[i..], "Invalid jet")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if jets.is_empty() {
+        return Err(SolveError::new("No jets"));
+    }
+    Ok(jets)
+}
+
+impl Jet {
+    fn push(self, rock: &[u8]) -> Option<Vec<u8>> {
+        match self {
+            Jet::Left if rock.iter().all(|r| r & LEFT_WALL == 0) => Some(rock.iter().map(|r| r << 1).collect()),
+            Jet::Right if rock.iter().all(|r| r & RIGHT_WALL == 0) => Some(rock.iter().map(|r| r >> 1).collect()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Hash)]
+struct State {
+    rock_index: usize,
+    jet_index: usize,
+    profile: [usize; WIDTH],
+}
+
+struct Chamber {
+    rows: Vec<u8>,
+    // The height of the topmost rock in each column, kept up to date as rocks land.
+    tops: [usize; WIDTH],
+    jets: Vec<Jet>,
+    jet_index: usize,
+    rock_index: usize,
+}
+
+impl Chamber {
+    fn new(jets: Vec<Jet>) -> Self {
+        Self { rows: vec![], tops: [0; WIDTH], jets, jet_index: 0, rock_index: 0 }
+    }
+
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn collides(&self, rock: &[u8], y: usize) -> bool {
+        rock.iter().enumerate()
+            .any(|(i, r)| self.rows.get(y + i).is_some_and(|row| row & r != 0))
+    }
+
+    fn drop_rock(&mut self) {
+        let mut rock = ROCKS[self.rock_index].to_vec();
+        self.rock_index = (self.rock_index + 1) % ROCKS.len();
+
+        let mut y = self.height() + 3;
+        loop {
+            let jet = self.jets[self.jet_index];
+            self.jet_index = (self.jet_index + 1) % self.jets.len();
+
+            if let Some(pushed) = jet.push(&rock) {
+                if !self.collides(&pushed, y) {
+                    rock = pushed;
+                }
+            }
+
+            if y == 0 || self.collides(&rock, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+
+        for (i, r) in rock.into_iter().enumerate() {
+            if y + i == self.rows.len() {
+                self.rows.push(0);
+            }
+            self.rows[y + i] |= r;
+            for (c, top) in self.tops.iter_mut().enumerate() {
+                if r & (LEFT_WALL >> c) != 0 {
+                    *top = (*top).max(y + i + 1);
+                }
+            }
+        }
+    }
+
+    // The depth of the topmost rock in each column, which is what the next rocks can interact with.
+    fn state(&self) -> State {
+        let profile = self.tops.map(|top| (self.height() - top).min(MAX_DEPTH));
+        State { rock_index: self.rock_index, jet_index: self.jet_index, profile }
+    }
+}
+
+fn tower_height(jets: &[Jet], rocks: usize) -> usize {
+    let mut chamber = Chamber::new(jets.to_vec());
+
+    let mut seen = HashMap::new();
+    let mut skipped_height = 0;
+    let mut dropped = 0;
+    while dropped < rocks {
+        chamber.drop_rock();
+        dropped += 1;
+
+        if skipped_height > 0 {
+            continue;
+        }
+
+        if let Some((previous_dropped, previous_height)) = seen.insert(chamber.state(), (dropped, chamber.height())) {
+            let cycle_length = dropped - previous_dropped;
+            let cycles = (rocks - dropped) / cycle_length;
+            skipped_height = cycles * (chamber.height() - previous_height);
+            dropped += cycles * cycle_length;
+        }
+    }
+
+    chamber.height() + skipped_height
+}
+
+struct Day17;
+
+impl Solution for Day17 {
+    type Parsed<'a> = Vec<Jet>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        parse_jets(input)
+    }
+
+    fn part1(jets: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(tower_height(jets, 2022).into())
+    }
+}
+
+impl SecondPart for Day17 {
+    fn part2(jets: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(tower_height(jets, 1000000000000).into())
+    }
+}
+
+const EXAMPLE_INPUT: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day17::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_17);
+    }
+
+    #[test]
+    fn test_single_jet() {
+        // Rocks pushed against one wall leave the columns at the other wall empty for good.
+        for (jets, part1, part2) in [("<", 4448, 2200000000000), (">", 5256, 2600000000000)] {
+            let report = DAY_17.solver.solve(jets).unwrap();
+            assert_eq!(Ok(Answer::Integer(part1)), report.part1.unwrap().result);
+            assert_eq!(Ok(Answer::Integer(part2)), report.part2.unwrap().result);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::{HashSet, VecDeque};
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, parse, SecondPart, SolveError, Solution};
+
+pub const DAY_18: Day = Day {
+    solver: DaySolver::new::<Day18>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("64"), Some("58")),
+    ],
+};
+
+type Cube = (i32, i32, i32);
+
+const SIDES: [Cube; 6] = [(1, 0, 0), (-1, 0, 0), (0, 1, 0), (0, -1, 0), (0, 0, 1), (0, 0, -1)];
+
+fn parse_cube(s: &str) -> Result<Cube, SolveError> {
+    let [x, y, z] = s.split(',').collect::<Vec<_>>()[..] else {
+        return Err(SolveError::at(s, "Expected three coordinates"));
+    };
+    Ok((parse(x)?, parse(y)?, parse(z)?))
+}
+
+fn neighbours((x, y, z): Cube) -> impl Iterator<Item=Cube> {
+    SIDES.into_iter().map(move |(dx, dy, dz)| (x + dx, y + dy, z + dz))
+}
+
+struct Day18;
+
+impl Solution for Day18 {
+    type Parsed<'a> = HashSet<Cube>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        let cubes = input.lines().map(parse_cube).collect::<Result<HashSet<_>, _>>()?;
+        if cubes.is_empty() {
+            return Err(SolveError::new("No cubes"));
+        }
+        Ok(cubes)
+    }
+
+    fn part1(cubes: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(cubes.iter()
+            .flat_map(|&c| neighbours(c))
+            .filter(|n| !cubes.contains(n))
+            .count().into())
+    }
+}
+
+impl SecondPart for Day18 {
+    fn part2(cubes: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        // Flood fill the air surrounding the droplet within a bounding box with one unit of padding.
+        let min = cubes.iter().fold((i32::MAX, i32::MAX, i32::MAX), |m, c| (m.0.min(c.0 - 1), m.1.min(c.1 - 1), m.2.min(c.2 - 1)));
+        let max = cubes.iter().fold((i32::MIN, i32::MIN, i32::MIN), |m, c| (m.0.max(c.0 + 1), m.1.max(c.1 + 1), m.2.max(c.2 + 1)));
+        let in_bounds = |c: &Cube| (min.0..=max.0).contains(&c.0) && (min.1..=max.1).contains(&c.1) && (min.2..=max.2).contains(&c.2);
+
+        let mut outside = HashSet::from([min]);
+        let mut queue = VecDeque::from([min]);
+        let mut exterior = 0;
+        while let Some(cube) = queue.pop_front() {
+            for neighbour in neighbours(cube).filter(in_bounds) {
+                if cubes.contains(&neighbour) {
+                    exterior += 1;
+                } else if outside.insert(neighbour) {
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+        Ok(exterior.into())
+    }
+}
+
+const EXAMPLE_INPUT: &str = "\
+2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day18::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_18);
+    }
+
+    #[test]
+    fn test_single_cube() {
+        let cubes = Day18::parse("1,1,1").unwrap();
+        assert_eq!(Answer::Integer(6), Day18::part1(&cubes).unwrap());
+        assert_eq!(Answer::Integer(6), Day18::part2(&cubes).unwrap());
+    }
+}
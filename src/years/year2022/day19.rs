@@ -0,0 +1,160 @@
+use std::cmp::max;
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, parse, SecondPart, SolveError, Solution};
+
+pub const DAY_19: Day = Day {
+    solver: DaySolver::new::<Day19>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("33"), Some("3472")),
+    ],
+};
+
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+struct Blueprint {
+    id: u32,
+    // Cost in ore, clay and obsidian of each robot type.
+    costs: [[u32; 3]; 4],
+    // Spending is limited to one robot per minute, so more robots than the most expensive cost is wasteful.
+    max_robots: [u32; 3],
+}
+
+impl TryFrom<&str> for Blueprint {
+    type Error = SolveError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let numbers = s
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|n| !n.is_empty())
+            .map(parse)
+            .collect::<Result<Vec<u32>, _>>()?;
+        let [id, ore_ore, clay_ore, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian] = numbers[..] else {
+            return Err(SolveError::at(s.trim_start(), "Invalid blueprint"));
+        };
+
+        let costs = [
+            [ore_ore, 0, 0],
+            [clay_ore, 0, 0],
+            [obsidian_ore, obsidian_clay, 0],
+            [geode_ore, 0, geode_obsidian],
+        ];
+        let mut max_robots = [0; 3];
+        for cost in costs {
+            for resource in [ORE, CLAY, OBSIDIAN] {
+                max_robots[resource] = max(max_robots[resource], cost[resource]);
+            }
+        }
+
+        Ok(Self { id, costs, max_robots })
+    }
+}
+
+impl Blueprint {
+    fn max_geodes(&self, time: u32) -> u32 {
+        let mut best = 0;
+        self.search(time, [1, 0, 0, 0], [0; 4], &mut best);
+        best
+    }
+
+    // Branches on which robot to build next, skipping the minutes spent waiting for its resources.
+    fn search(&self, time_remaining: u32, robots: [u32; 4], resources: [u32; 4], best: &mut u32) {
+        let idle_geodes = resources[GEODE] + robots[GEODE] * time_remaining;
+        *best = max(*best, idle_geodes);
+
+        // Even building a geode robot every remaining minute cannot beat the best.
+        if idle_geodes + time_remaining * time_remaining.saturating_sub(1) / 2 <= *best {
+            return;
+        }
+
+        for robot in [GEODE, OBSIDIAN, CLAY, ORE] {
+            if robot != GEODE && robots[robot] * time_remaining + resources[robot] >= self.max_robots[robot] * time_remaining {
+                continue;
+            }
+
+            let Some(wait) = self.wait_for(robot, &robots, &resources) else {
+                continue;
+            };
+            let elapsed = wait + 1;
+            if elapsed >= time_remaining {
+                continue;
+            }
+
+            let mut new_resources = resources;
+            for resource in [ORE, CLAY, OBSIDIAN, GEODE] {
+                new_resources[resource] += robots[resource] * elapsed;
+            }
+            for resource in [ORE, CLAY, OBSIDIAN] {
+                new_resources[resource] -= self.costs[robot][resource];
+            }
+            let mut new_robots = robots;
+            new_robots[robot] += 1;
+
+            self.search(time_remaining - elapsed, new_robots, new_resources, best);
+        }
+    }
+
+    fn wait_for(&self, robot: usize, robots: &[u32; 4], resources: &[u32; 4]) -> Option<u32> {
+        let mut wait = 0;
+        for resource in [ORE, CLAY, OBSIDIAN] {
+            let cost = self.costs[robot][resource];
+            if cost <= resources[resource] {
+                continue;
+            }
+            if robots[resource] == 0 {
+                return None;
+            }
+            wait = max(wait, (cost - resources[resource]).div_ceil(robots[resource]));
+        }
+        Some(wait)
+    }
+}
+
+fn parse_blueprints(input: &str) -> Result<Vec<Blueprint>, SolveError> {
+    input.split("Blueprint")
+        .filter(|b| !b.trim().is_empty())
+        .map(Blueprint::try_from)
+        .collect()
+}
+
+struct Day19;
+
+impl Solution for Day19 {
+    type Parsed<'a> = Vec<Blueprint>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        parse_blueprints(input)
+    }
+
+    fn part1(blueprints: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(blueprints.iter()
+            .map(|b| b.id * b.max_geodes(24))
+            .sum::<u32>().into())
+    }
+}
+
+impl SecondPart for Day19 {
+    fn part2(blueprints: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(blueprints.iter()
+            .take(3)
+            .map(|b| b.max_geodes(32))
+            .product::<u32>().into())
+    }
+}
+
+const EXAMPLE_INPUT: &str = "\
+Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day19::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_19);
+    }
+}
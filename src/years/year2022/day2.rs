@@ -0,0 +1,172 @@
+use Move::*;
+use Outcome::*;
+
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, SecondPart, SolveError, Solution, split_once};
+
+pub const DAY_2: Day = Day {
+    solver: DaySolver::new::<Day2>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("15"), Some("12")),
+    ],
+};
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+enum Move {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Move {
+    fn beats(self) -> Move {
+        match self {
+            Rock => Scissors,
+            Paper => Rock,
+            Scissors => Paper,
+        }
+    }
+
+    fn beaten_by(self) -> Move {
+        match self {
+            Rock => Paper,
+            Paper => Scissors,
+            Scissors => Rock,
+        }
+    }
+
+    fn score(self) -> i32 {
+        match self {
+            Rock => 1,
+            Paper => 2,
+            Scissors => 3,
+        }
+    }
+}
+
+impl TryFrom<&str> for Move {
+    type Error = SolveError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "A" | "X" => Ok(Rock),
+            "B" | "Y" => Ok(Paper),
+            "C" | "Z" => Ok(Scissors),
+            _ => Err(SolveError::at(s, "Invalid move")),
+        }
+    }
+}
+
+// The second column is only interpreted once it is known which part is being solved.
+struct Round<'a> {
+    observation: Move,
+    action: &'a str,
+}
+
+impl<'a> TryFrom<&'a str> for Round<'a> {
+    type Error = SolveError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let (opponent, play) = split_once(s.trim(), " ")?;
+
+        Ok(Round {
+            observation: opponent.try_into()?,
+            action: play,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Outcome {
+    Win,
+    Draw,
+    Loss,
+}
+
+impl Outcome {
+    fn score(self) -> i32 {
+        match self {
+            Win => 6,
+            Draw => 3,
+            Loss => 0,
+        }
+    }
+}
+
+impl TryFrom<&str> for Outcome {
+    type Error = SolveError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "X" => Ok(Loss),
+            "Y" => Ok(Draw),
+            "Z" => Ok(Win),
+            _ => Err(SolveError::at(s, "Invalid outcome")),
+        }
+    }
+}
+
+fn outcome(play: Move, opponent: Move) -> Outcome {
+    if play == opponent {
+        Draw
+    } else if play == opponent.beaten_by() {
+        Win
+    } else {
+        Loss
+    }
+}
+
+fn move_from_outcome(opponent: Move, outcome: Outcome) -> Move {
+    match outcome {
+        Draw => opponent,
+        Win => opponent.beaten_by(),
+        Loss => opponent.beats(),
+    }
+}
+
+fn solver<'a, T: TryFrom<&'a str, Error=SolveError>, F: Fn(Move, T) -> i32>(rounds: &[Round<'a>], mapper: F) -> Result<Answer, SolveError> {
+    Ok(rounds.iter()
+        .map(|r| Ok(mapper(r.observation, r.action.try_into()?)))
+        .sum::<Result<i32, SolveError>>()?
+        .into())
+}
+
+struct Day2;
+
+impl Solution for Day2 {
+    type Parsed<'a> = Vec<Round<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        input.lines().map(Round::try_from).collect()
+    }
+
+    fn part1(rounds: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        solver(rounds, |observation, action: Move| outcome(action, observation).score() + action.score())
+    }
+}
+
+impl SecondPart for Day2 {
+    fn part2(rounds: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        solver(rounds, |observation, action: Outcome| action.score() + move_from_outcome(observation, action).score())
+    }
+}
+const EXAMPLE_INPUT: &str = "\
+A Y
+B X
+C Z";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day2::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_2);
+    }
+
+    #[test]
+    fn test_invalid_move() {
+        assert_eq!("Invalid move", DAY_2.solver.solve("A Y\nD X").unwrap_err().message);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, parse, SecondPart, SolveError, Solution};
+
+pub const DAY_20: Day = Day {
+    solver: DaySolver::new::<Day20>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("3"), Some("1623178306")),
+    ],
+};
+
+const DECRYPTION_KEY: i64 = 811589153;
+
+fn grove_coordinates(numbers: &[i64], key: i64, rounds: usize) -> Result<i64, SolveError> {
+    let numbers = numbers.iter()
+        .map(|n| n.checked_mul(key).ok_or_else(overflow))
+        .collect::<Result<Vec<_>, _>>()?;
+    let zero_index = numbers.iter().position(|&n| n == 0).unwrap();
+
+    // Mixes the original indexes rather than the values so duplicates stay distinguishable.
+    let mut mixed = (0..numbers.len()).collect::<Vec<_>>();
+    // A lone number has nowhere to move, and leaves nothing to move it around.
+    let rounds = if numbers.len() > 1 { rounds } else { 0 };
+    for _ in 0..rounds {
+        for (original_index, &number) in numbers.iter().enumerate() {
+            let position = mixed.iter().position(|&i| i == original_index).unwrap();
+            mixed.remove(position);
+            let length = mixed.len() as i64;
+            let new_position = (position as i64 + number.rem_euclid(length)) % length;
+            mixed.insert(new_position as usize, original_index);
+        }
+    }
+
+    let zero_position = mixed.iter().position(|&i| i == zero_index).unwrap();
+    [1000, 2000, 3000].into_iter()
+        .try_fold(0i64, |sum, offset| sum.checked_add(numbers[mixed[(zero_position + offset) % mixed.len()]]))
+        .ok_or_else(overflow)
+}
+
+fn overflow() -> SolveError {
+    SolveError::new("Overflow")
+}
+
+struct Day20;
+
+impl Solution for Day20 {
+    type Parsed<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        let numbers = input.lines().map(parse::<i64>).collect::<Result<Vec<_>, _>>()?;
+        if !numbers.contains(&0) {
+            return Err(SolveError::new("No zero in the file"));
+        }
+        Ok(numbers)
+    }
+
+    fn part1(numbers: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(grove_coordinates(numbers, 1, 1)?.into())
+    }
+}
+
+impl SecondPart for Day20 {
+    fn part2(numbers: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(grove_coordinates(numbers, DECRYPTION_KEY, 10)?.into())
+    }
+}
+
+const EXAMPLE_INPUT: &str = "\
+1
+2
+-3
+3
+-2
+0
+4";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day20::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_20);
+    }
+
+    #[test]
+    fn test_single_number() {
+        let report = DAY_20.solver.solve("0\n").unwrap();
+        assert_eq!(Ok(Answer::Integer(0)), report.part1.unwrap().result);
+        assert_eq!(Ok(Answer::Integer(0)), report.part2.unwrap().result);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let numbers = Day20::parse("0\n9223372036854775807\n1").unwrap();
+        let Err(error) = Day20::part2(&numbers) else { panic!("expected error"); };
+        assert_eq!("Overflow", error.message);
+    }
+}
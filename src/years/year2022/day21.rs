@@ -0,0 +1,268 @@
+use std::collections::{HashMap, HashSet};
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, parse, SecondPart, SolveError, Solution, split_once};
+use crate::years::year2022::day21::Job::*;
+use crate::years::year2022::day21::Operator::*;
+
+pub const DAY_21: Day = Day {
+    solver: DaySolver::new::<Day21>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("152"), Some("301")),
+    ],
+};
+
+const ROOT: &str = "root";
+const HUMAN: &str = "humn";
+
+#[derive(Copy, Clone)]
+enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl TryFrom<&str> for Operator {
+    type Error = SolveError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "+" => Ok(Add),
+            "-" => Ok(Subtract),
+            "*" => Ok(Multiply),
+            "/" => Ok(Divide),
+            _ => Err(SolveError::at(s, "Invalid operator")),
+        }
+    }
+}
+
+impl Operator {
+    fn apply(self, left: i64, right: i64) -> Result<i64, SolveError> {
+        match self {
+            Add => left.checked_add(right).ok_or_else(overflow),
+            Subtract => left.checked_sub(right).ok_or_else(overflow),
+            Multiply => left.checked_mul(right).ok_or_else(overflow),
+            Divide if right == 0 => Err(SolveError::new("Division by zero")),
+            Divide => left.checked_div(right).ok_or_else(overflow),
+        }
+    }
+
+    // Solves `target = unknown <op> right` for the unknown.
+    fn solve_left(self, target: i64, right: i64) -> Result<i64, SolveError> {
+        match self {
+            Add => target.checked_sub(right).ok_or_else(overflow),
+            Subtract => target.checked_add(right).ok_or_else(overflow),
+            Multiply => exact_division(target, right),
+            Divide => target.checked_mul(right).ok_or_else(overflow),
+        }
+    }
+
+    // Solves `target = left <op> unknown` for the unknown.
+    fn solve_right(self, target: i64, left: i64) -> Result<i64, SolveError> {
+        match self {
+            Add => target.checked_sub(left).ok_or_else(overflow),
+            Subtract => left.checked_sub(target).ok_or_else(overflow),
+            Multiply => exact_division(target, left),
+            Divide => exact_division(left, target),
+        }
+    }
+}
+
+fn overflow() -> SolveError {
+    SolveError::new("Overflow")
+}
+
+fn exact_division(dividend: i64, divisor: i64) -> Result<i64, SolveError> {
+    if divisor == 0 || dividend.checked_rem(divisor).ok_or_else(overflow)? != 0 {
+        return Err(SolveError::new("No integer solution"));
+    }
+    dividend.checked_div(divisor).ok_or_else(overflow)
+}
+
+enum Job<'a> {
+    Number(i64),
+    Operation(&'a str, Operator, &'a str),
+}
+
+impl<'a> TryFrom<&'a str> for Job<'a> {
+    type Error = SolveError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        if s.starts_with(|c: char| c.is_ascii_digit()) {
+            return Ok(Number(parse(s)?));
+        }
+
+        let [left, operator, right] = s.split(' ').collect::<Vec<_>>()[..] else {
+            return Err(SolveError::at(s, "Invalid job"));
+        };
+        Ok(Operation(left, operator.try_into()?, right))
+    }
+}
+
+struct Monkeys<'a> {
+    jobs: HashMap<&'a str, Job<'a>>,
+}
+
+impl<'a> TryFrom<&'a str> for Monkeys<'a> {
+    type Error = SolveError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let jobs = s.lines()
+            .map(|l| {
+                let (name, job) = split_once(l, ": ")?;
+                Ok((name, job.try_into()?))
+            })
+            .collect::<Result<_, SolveError>>()?;
+        let monkeys = Self { jobs };
+        monkeys.check_acyclic()?;
+        Ok(monkeys)
+    }
+}
+
+impl Monkeys<'_> {
+    // Monkeys waiting on each other would never finish, and would recurse forever below, so they are rejected upfront.
+    fn check_acyclic(&self) -> Result<(), SolveError> {
+        let mut finished = HashSet::new();
+        for &start in self.jobs.keys() {
+            let mut waiting = HashSet::new();
+            let mut stack = vec![(start, false)];
+            while let Some((name, done)) = stack.pop() {
+                if done {
+                    waiting.remove(name);
+                    finished.insert(name);
+                    continue;
+                }
+                if finished.contains(name) {
+                    continue;
+                }
+                if !waiting.insert(name) {
+                    return Err(SolveError::at(name, "Cyclic job"));
+                }
+
+                stack.push((name, true));
+                if let Some(&Operation(left, _, right)) = self.jobs.get(name) {
+                    stack.extend([(left, false), (right, false)]);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn job(&self, name: &str) -> Result<&Job<'_>, SolveError> {
+        self.jobs.get(name).ok_or_else(|| SolveError::at(name, "Unknown monkey"))
+    }
+
+    fn evaluate(&self, name: &str) -> Result<i64, SolveError> {
+        match *self.job(name)? {
+            Number(n) => Ok(n),
+            Operation(left, operator, right) => operator.apply(self.evaluate(left)?, self.evaluate(right)?),
+        }
+    }
+
+    fn depends_on_human(&self, name: &str) -> Result<bool, SolveError> {
+        if name == HUMAN {
+            return Ok(true);
+        }
+
+        match *self.job(name)? {
+            Number(_) => Ok(false),
+            Operation(left, _, right) => Ok(self.depends_on_human(left)? || self.depends_on_human(right)?),
+        }
+    }
+
+    // Inverts the operations on the path from `name` down to the human so `name` evaluates to `target`.
+    fn solve_for_human(&self, name: &str, target: i64) -> Result<i64, SolveError> {
+        if name == HUMAN {
+            return Ok(target);
+        }
+
+        let Operation(left, operator, right) = *self.job(name)? else {
+            return Err(SolveError::at(name, "Human is not below this monkey"));
+        };
+
+        if self.depends_on_human(left)? {
+            self.solve_for_human(left, operator.solve_left(target, self.evaluate(right)?)?)
+        } else {
+            self.solve_for_human(right, operator.solve_right(target, self.evaluate(left)?)?)
+        }
+    }
+}
+
+struct Day21;
+
+impl Solution for Day21 {
+    type Parsed<'a> = Monkeys<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Monkeys::try_from(input)
+    }
+
+    fn part1(monkeys: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(monkeys.evaluate(ROOT)?.into())
+    }
+}
+
+impl SecondPart for Day21 {
+    fn part2(monkeys: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        let Operation(left, _, right) = *monkeys.job(ROOT)? else {
+            return Err(SolveError::new("Root has no operation"));
+        };
+
+        let human = if monkeys.depends_on_human(left)? {
+            monkeys.solve_for_human(left, monkeys.evaluate(right)?)?
+        } else {
+            monkeys.solve_for_human(right, monkeys.evaluate(left)?)?
+        };
+        Ok(human.into())
+    }
+}
+
+const EXAMPLE_INPUT: &str = "\
+root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day21::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_21);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let monkeys = Day21::parse("root: pppw + sjmn\npppw: humn / zczc\nsjmn: 9223372036854775807\nzczc: 2\nhumn: 1").unwrap();
+        let Err(error) = Day21::part2(&monkeys) else { panic!("expected error"); };
+        assert_eq!("Overflow", error.message);
+
+        let monkeys = Day21::parse("root: sjmn * zczc\nsjmn: 9223372036854775807\nzczc: 2\nhumn: 1").unwrap();
+        let Err(error) = Day21::part1(&monkeys) else { panic!("expected error"); };
+        assert_eq!("Overflow", error.message);
+    }
+
+    #[test]
+    fn test_cyclic_job() {
+        let input = "root: a + b\na: b + root\nb: 1\nhumn: 2";
+        let Err(error) = DAY_21.solver.solve(input) else {
+            panic!("expected error");
+        };
+
+        assert_eq!("Cyclic job", error.message);
+        assert!(error.location.is_some());
+    }
+}
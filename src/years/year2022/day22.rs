@@ -0,0 +1,332 @@
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, SecondPart, SolveError, Solution, split_once};
+use crate::years::year2022::day22::Instruction::*;
+
+pub const DAY_22: Day = Day {
+    solver: DaySolver::new::<Day22>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("6032"), Some("5031")),
+    ],
+};
+
+// Row and column deltas for facing right, down, left and up, in the order used by the password.
+const DIRECTIONS: [(i64, i64); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+enum Instruction {
+    Move(usize),
+    TurnLeft,
+    TurnRight,
+}
+
+fn parse_path(s: &str) -> Result<Vec<Instruction>, SolveError> {
+    let s = s.trim();
+    let mut instructions = vec![];
+    let mut steps = None;
+    for (i, c) in s.bytes().enumerate() {
+        if c.is_ascii_digit() {
+            steps = Some(steps.unwrap_or(0) * 10 + (c - b'0') as usize);
+            continue;
+        }
+
+        if let Some(steps) = steps.take() {
+            instructions.push(Move(steps));
+        }
+        instructions.push(match c {
+            b'L' => TurnLeft,
+            b'R' => TurnRight,
+            _ => return Err(SolveError::at(&s[i..], "Invalid instruction")),
+        });
+    }
+    if let Some(steps) = steps {
+        instructions.push(Move(steps));
+    }
+    Ok(instructions)
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct Position {
+    row: usize,
+    col: usize,
+    facing: usize,
+}
+
+impl Position {
+    fn password(&self) -> usize {
+        1000 * (self.row + 1) + 4 * (self.col + 1) + self.facing
+    }
+}
+
+struct Board {
+    tiles: Vec<Vec<u8>>,
+}
+
+impl From<&str> for Board {
+    fn from(s: &str) -> Self {
+        Self { tiles: s.lines().map(|l| l.as_bytes().to_vec()).collect() }
+    }
+}
+
+impl Board {
+    fn tile(&self, row: i64, col: i64) -> u8 {
+        if row < 0 || col < 0 {
+            return b' ';
+        }
+        self.tiles.get(row as usize)
+            .and_then(|r| r.get(col as usize))
+            .cloned()
+            .unwrap_or(b' ')
+    }
+
+    fn is_full_face(&self, row: usize, col: usize, size: usize) -> bool {
+        (row * size..(row + 1) * size)
+            .all(|r| (col * size..(col + 1) * size).all(|c| self.tile(r as i64, c as i64) != b' '))
+    }
+
+    fn start(&self) -> Option<Position> {
+        let col = self.tiles.first()?.iter().position(|&t| t == b'.')?;
+        Some(Position { row: 0, col, facing: 0 })
+    }
+
+    fn walk<W: Fn(&Board, Position) -> Position>(&self, path: &[Instruction], wrap: W) -> Result<Position, SolveError> {
+        let mut position = self.start().ok_or_else(|| SolveError::new("No open tile on the top row"))?;
+        for instruction in path {
+            match instruction {
+                Move(steps) => {
+                    for _ in 0..*steps {
+                        let next = self.step(position, &wrap);
+                        if self.tile(next.row as i64, next.col as i64) == b'#' {
+                            break;
+                        }
+                        position = next;
+                    }
+                }
+                TurnLeft => position.facing = (position.facing + 3) % 4,
+                TurnRight => position.facing = (position.facing + 1) % 4,
+            }
+        }
+        Ok(position)
+    }
+
+    fn step<W: Fn(&Board, Position) -> Position>(&self, position: Position, wrap: &W) -> Position {
+        let (dr, dc) = DIRECTIONS[position.facing];
+        let (row, col) = (position.row as i64 + dr, position.col as i64 + dc);
+        if self.tile(row, col) == b' ' {
+            wrap(self, position)
+        } else {
+            Position { row: row as usize, col: col as usize, facing: position.facing }
+        }
+    }
+}
+
+fn flat_wrap(board: &Board, position: Position) -> Position {
+    let (dr, dc) = DIRECTIONS[position.facing];
+    let (mut row, mut col) = (position.row as i64, position.col as i64);
+    while board.tile(row - dr, col - dc) != b' ' {
+        row -= dr;
+        col -= dc;
+    }
+    Position { row: row as usize, col: col as usize, facing: position.facing }
+}
+
+type Vector = [i32; 3];
+
+fn negate(v: Vector) -> Vector {
+    v.map(|e| -e)
+}
+
+// A face of the net together with the directions its columns, rows and outward normal point in once folded.
+#[derive(Copy, Clone)]
+struct Face {
+    row: usize,
+    col: usize,
+    right: Vector,
+    down: Vector,
+    normal: Vector,
+}
+
+impl Face {
+    fn direction(&self, facing: usize) -> Vector {
+        match facing {
+            0 => self.right,
+            1 => self.down,
+            2 => negate(self.right),
+            _ => negate(self.down),
+        }
+    }
+
+    fn facing(&self, direction: Vector) -> usize {
+        (0..4).find(|&f| self.direction(f) == direction).unwrap()
+    }
+}
+
+struct Cube {
+    size: usize,
+    faces: Vec<Face>,
+}
+
+impl TryFrom<&Board> for Cube {
+    type Error = SolveError;
+
+    fn try_from(board: &Board) -> Result<Self, Self::Error> {
+        let area = board.tiles.iter().flatten().filter(|&&t| t != b' ').count();
+        let size = ((area / 6) as f64).sqrt() as usize;
+        if size == 0 || area != 6 * size * size {
+            return Err(SolveError::new("Map is not a cube net"));
+        }
+
+        // Folds each face relative to an already placed neighbour in the net, starting from an arbitrary frame.
+        let first_col = board.tiles[0].iter().position(|&t| t != b' ')
+            .ok_or_else(|| SolveError::new("Map does not start on the top row"))? / size;
+        let mut faces = vec![Face { row: 0, col: first_col, right: [1, 0, 0], down: [0, 1, 0], normal: [0, 0, 1] }];
+        let mut i = 0;
+        while i < faces.len() {
+            let face = faces[i];
+            for (facing, (dr, dc)) in DIRECTIONS.into_iter().enumerate() {
+                let (row, col) = (face.row as i64 + dr, face.col as i64 + dc);
+                if board.tile(row * size as i64, col * size as i64) == b' ' {
+                    continue;
+                }
+                let (row, col) = (row as usize, col as usize);
+                if faces.iter().any(|f| f.row == row && f.col == col) {
+                    continue;
+                }
+
+                let (right, down, normal) = match facing {
+                    0 => (negate(face.normal), face.down, face.right),
+                    1 => (face.right, negate(face.normal), face.down),
+                    2 => (face.normal, face.down, negate(face.right)),
+                    _ => (face.right, face.normal, negate(face.down)),
+                };
+                faces.push(Face { row, col, right, down, normal });
+            }
+            i += 1;
+        }
+        let overlapping = faces.iter().enumerate().any(|(i, f)| faces[..i].iter().any(|g| g.normal == f.normal));
+        if faces.len() != 6 || overlapping || faces.iter().any(|f| !board.is_full_face(f.row, f.col, size)) {
+            return Err(SolveError::new("Map is not a cube net"));
+        }
+
+        Ok(Self { size, faces })
+    }
+}
+
+impl Cube {
+    fn wrap(&self, position: Position) -> Position {
+        let s = self.size;
+        let from = self.faces.iter()
+            .find(|f| f.row == position.row / s && f.col == position.col / s)
+            .unwrap();
+        let (row, col) = (position.row % s, position.col % s);
+
+        // The edge is crossed onto the face pointing in the direction of travel, heading away from the current face.
+        let exit = from.direction(position.facing);
+        let to = self.faces.iter().find(|f| f.normal == exit).unwrap();
+        let facing = to.facing(negate(from.normal));
+
+        // The offset along the shared edge, measured from the same corner on both faces.
+        let (along, offset) = if position.facing.is_multiple_of(2) { (from.down, row) } else { (from.right, col) };
+
+        let (mut new_row, mut new_col) = match facing {
+            0 | 1 => (0, 0),
+            2 => (0, s - 1),
+            _ => (s - 1, 0),
+        };
+        match to.facing(along) {
+            0 => new_col = offset,
+            1 => new_row = offset,
+            2 => new_col = s - 1 - offset,
+            _ => new_row = s - 1 - offset,
+        }
+
+        Position { row: to.row * s + new_row, col: to.col * s + new_col, facing }
+    }
+}
+
+fn parse(input: &str) -> Result<(Board, Vec<Instruction>), SolveError> {
+    let (board, path) = split_once(input, "\n\n")?;
+    Ok((board.into(), parse_path(path)?))
+}
+
+struct Day22;
+
+impl Solution for Day22 {
+    type Parsed<'a> = (Board, Vec<Instruction>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        parse(input)
+    }
+
+    fn part1((board, path): &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(board.walk(path, flat_wrap)?.password().into())
+    }
+}
+
+impl SecondPart for Day22 {
+    fn part2((board, path): &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        let cube = Cube::try_from(board)?;
+        Ok(board.walk(path, |_, p| cube.wrap(p))?.password().into())
+    }
+}
+
+const EXAMPLE_INPUT: &str = "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day22::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_22);
+    }
+
+    // The net shape of the puzzle inputs, which differs from the example.
+    fn input_shaped_board(size: usize) -> Board {
+        let layout = [" ##", " # ", "## ", "#  "];
+        let tiles = layout.iter()
+            .flat_map(|l| {
+                let row = l.bytes().flat_map(|b| vec![if b == b'#' { b'.' } else { b' ' }; size]).collect::<Vec<_>>();
+                vec![row; size]
+            })
+            .collect();
+        Board { tiles }
+    }
+
+    fn assert_wraps_back(board: &Board) {
+        let cube = Cube::try_from(board).unwrap();
+        for (row, tiles) in board.tiles.iter().enumerate() {
+            for (col, _) in tiles.iter().enumerate().filter(|(_, &t)| t != b' ') {
+                for facing in 0..4 {
+                    let position = Position { row, col, facing };
+                    let next = board.step(position, &|_: &Board, p| cube.wrap(p));
+                    let turned = Position { facing: (next.facing + 2) % 4, ..next };
+                    let back = board.step(turned, &|_: &Board, p| cube.wrap(p));
+                    assert_eq!(Position { facing: (facing + 2) % 4, ..position }, back);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_example_cube_wraps_back() {
+        assert_wraps_back(&parse(EXAMPLE_INPUT).unwrap().0);
+    }
+
+    #[test]
+    fn test_input_shaped_cube_wraps_back() {
+        assert_wraps_back(&input_shaped_board(50));
+    }
+}
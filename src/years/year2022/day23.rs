@@ -0,0 +1,124 @@
+use std::collections::{HashMap, HashSet};
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, SecondPart, SolveError, Solution};
+
+pub const DAY_23: Day = Day {
+    solver: DaySolver::new::<Day23>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("110"), Some("20")),
+    ],
+};
+
+type Position = (i32, i32);
+
+// Each direction to consider, with the three neighbouring offsets that must be free to move in that direction.
+const DIRECTIONS: [(Position, [Position; 3]); 4] = [
+    ((0, -1), [(-1, -1), (0, -1), (1, -1)]),
+    ((0, 1), [(-1, 1), (0, 1), (1, 1)]),
+    ((-1, 0), [(-1, -1), (-1, 0), (-1, 1)]),
+    ((1, 0), [(1, -1), (1, 0), (1, 1)]),
+];
+
+const NEIGHBOURS: [Position; 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+fn parse_elves(input: &str) -> Result<HashSet<Position>, SolveError> {
+    if let Some(invalid) = input.find(|c: char| c != '#' && c != '.' && c != '\n') {
+        return Err(SolveError::at(&input[invalid..], "Invalid tile"));
+    }
+
+    let elves = input.lines().enumerate()
+        .flat_map(|(y, l)| l.bytes().enumerate()
+            .filter(|&(_, b)| b == b'#')
+            .map(move |(x, _)| (x as i32, y as i32)))
+        .collect::<HashSet<_>>();
+    if elves.is_empty() {
+        return Err(SolveError::new("No elves"));
+    }
+    Ok(elves)
+}
+
+fn is_free(elves: &HashSet<Position>, (x, y): Position, offsets: &[Position]) -> bool {
+    offsets.iter().all(|(dx, dy)| !elves.contains(&(x + dx, y + dy)))
+}
+
+// Returns whether any elf moved.
+fn round(elves: &mut HashSet<Position>, round: usize) -> bool {
+    let mut proposals = HashMap::<Position, Vec<Position>>::new();
+    for &elf in elves.iter() {
+        if is_free(elves, elf, &NEIGHBOURS) {
+            continue;
+        }
+
+        let proposal = (0..DIRECTIONS.len())
+            .map(|i| DIRECTIONS[(round + i) % DIRECTIONS.len()])
+            .find(|(_, checks)| is_free(elves, elf, checks));
+        if let Some(((dx, dy), _)) = proposal {
+            proposals.entry((elf.0 + dx, elf.1 + dy)).or_default().push(elf);
+        }
+    }
+
+    let mut moved = false;
+    for (target, proposers) in proposals {
+        if let [elf] = proposers[..] {
+            elves.remove(&elf);
+            elves.insert(target);
+            moved = true;
+        }
+    }
+    moved
+}
+
+fn empty_ground(elves: &HashSet<Position>) -> i32 {
+    let (min_x, max_x) = (elves.iter().map(|e| e.0).min().unwrap(), elves.iter().map(|e| e.0).max().unwrap());
+    let (min_y, max_y) = (elves.iter().map(|e| e.1).min().unwrap(), elves.iter().map(|e| e.1).max().unwrap());
+    (max_x - min_x + 1) * (max_y - min_y + 1) - elves.len() as i32
+}
+
+struct Day23;
+
+impl Solution for Day23 {
+    type Parsed<'a> = HashSet<Position>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        parse_elves(input)
+    }
+
+    fn part1(elves: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        let mut elves = elves.clone();
+        for rounds in 0..10 {
+            round(&mut elves, rounds);
+        }
+        Ok(empty_ground(&elves).into())
+    }
+}
+
+impl SecondPart for Day23 {
+    fn part2(elves: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        let mut elves = elves.clone();
+        let mut rounds = 0;
+        while round(&mut elves, rounds) {
+            rounds += 1;
+        }
+        Ok((rounds + 1).into())
+    }
+}
+
+const EXAMPLE_INPUT: &str = "\
+....#..
+..###.#
+#...#.#
+.#...##
+#.###..
+##.#.##
+.#..#..";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day23::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_23);
+    }
+}
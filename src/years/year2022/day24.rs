@@ -0,0 +1,146 @@
+use std::collections::{HashSet, VecDeque};
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, SecondPart, SolveError, Solution};
+
+pub const DAY_24: Day = Day {
+    solver: DaySolver::new::<Day24>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("18"), Some("54")),
+    ],
+};
+
+type Position = (usize, usize);
+
+const MOVES: [(isize, isize); 5] = [(0, 0), (0, 1), (1, 0), (0, -1), (-1, 0)];
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+struct Valley {
+    tiles: Vec<Vec<u8>>,
+    // Dimensions of the inner area that the blizzards cycle through.
+    width: usize,
+    height: usize,
+    period: usize,
+    start: Position,
+    goal: Position,
+}
+
+impl TryFrom<&str> for Valley {
+    type Error = SolveError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        if let Some(invalid) = s.find(|c: char| !"#.<>^v\n".contains(c)) {
+            return Err(SolveError::at(&s[invalid..], "Invalid tile"));
+        }
+
+        let tiles = s.lines().map(|l| l.as_bytes().to_vec()).collect::<Vec<_>>();
+        let full_width = tiles.first().map_or(0, Vec::len);
+        if tiles.len() < 3 || full_width < 3 || tiles.iter().any(|r| r.len() != full_width) {
+            return Err(SolveError::new("Valley is not a walled rectangle"));
+        }
+        let inner_is_walled = tiles.iter().all(|r| r[0] == b'#' && r[full_width - 1] == b'#')
+            && tiles[1..tiles.len() - 1].iter().flatten().filter(|&&t| t == b'#').count() == 2 * (tiles.len() - 2);
+        if !inner_is_walled {
+            return Err(SolveError::new("Valley is not a walled rectangle"));
+        }
+
+        let width = full_width - 2;
+        let height = tiles.len() - 2;
+        let period = width * height / gcd(width, height);
+        let entrance = |row: &[u8]| row.iter().position(|&t| t == b'.').ok_or_else(|| SolveError::new("No entrance"));
+        let start = (0, entrance(&tiles[0])?);
+        let goal = (height + 1, entrance(&tiles[height + 1])?);
+
+        Ok(Self { tiles, width, height, period, start, goal })
+    }
+}
+
+impl Valley {
+    fn is_wall(&self, (row, col): Position) -> bool {
+        self.tiles[row][col] == b'#'
+    }
+
+    // Looks back along each axis for a blizzard which would be on the position at the given time.
+    fn has_blizzard(&self, (row, col): Position, time: usize) -> bool {
+        if row == 0 || row > self.height {
+            return false;
+        }
+
+        let (r, c) = (row - 1, col - 1);
+        let (w, h) = (self.width, self.height);
+        let (tw, th) = (time % w, time % h);
+        self.tiles[row][(c + w - tw) % w + 1] == b'>'
+            || self.tiles[row][(c + tw) % w + 1] == b'<'
+            || self.tiles[(r + h - th) % h + 1][col] == b'v'
+            || self.tiles[(r + th) % h + 1][col] == b'^'
+    }
+
+    fn neighbours(&self, (row, col): Position) -> impl Iterator<Item=Position> + '_ {
+        MOVES.into_iter()
+            .filter_map(move |(dr, dc)| Some((row.checked_add_signed(dr)?, col.checked_add_signed(dc)?)))
+            .filter(|&(r, c)| r < self.tiles.len() && c < self.tiles[r].len())
+            .filter(|&p| !self.is_wall(p))
+    }
+
+    // Returns the time of arrival at the goal.
+    fn cross(&self, from: Position, to: Position, start_time: usize) -> Result<usize, SolveError> {
+        let mut seen = HashSet::from([(from, start_time % self.period)]);
+        let mut queue = VecDeque::from([(from, start_time)]);
+        while let Some((position, time)) = queue.pop_front() {
+            if position == to {
+                return Ok(time);
+            }
+
+            let next_time = time + 1;
+            for next in self.neighbours(position) {
+                if !self.has_blizzard(next, next_time) && seen.insert((next, next_time % self.period)) {
+                    queue.push_back((next, next_time));
+                }
+            }
+        }
+        Err(SolveError::new("No way across the valley"))
+    }
+}
+
+struct Day24;
+
+impl Solution for Day24 {
+    type Parsed<'a> = Valley;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Valley::try_from(input)
+    }
+
+    fn part1(valley: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(valley.cross(valley.start, valley.goal, 0)?.into())
+    }
+}
+
+impl SecondPart for Day24 {
+    fn part2(valley: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        let there = valley.cross(valley.start, valley.goal, 0)?;
+        let back = valley.cross(valley.goal, valley.start, there)?;
+        Ok(valley.cross(valley.start, valley.goal, back)?.into())
+    }
+}
+
+const EXAMPLE_INPUT: &str = "\
+#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day24::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_24);
+    }
+}
@@ -0,0 +1,143 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, parse, SolveError, Solution};
+
+pub const DAY_25: Day = Day {
+    solver: DaySolver::without_part2::<Day25>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("2=-1=0"), None),
+    ],
+};
+
+// A number in balanced base 5, where the digits `=`, `-`, `0`, `1` and `2` are worth -2 to 2.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Snafu(i64);
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SnafuError {
+    InvalidDigit(char),
+    OutOfRange,
+}
+
+impl FromStr for Snafu {
+    type Err = SnafuError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.chars().try_fold(0, |value: i64, c| {
+            let digit = match c {
+                '2' => 2,
+                '1' => 1,
+                '0' => 0,
+                '-' => -1,
+                '=' => -2,
+                _ => return Err(SnafuError::InvalidDigit(c)),
+            };
+            value.checked_mul(5).and_then(|v| v.checked_add(digit)).ok_or(SnafuError::OutOfRange)
+        }).map(Snafu)
+    }
+}
+
+impl Display for Snafu {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.0 == 0 {
+            return write!(f, "0");
+        }
+
+        let mut digits = vec![];
+        let mut value = self.0;
+        while value != 0 {
+            let (digit, carry) = match value.rem_euclid(5) {
+                0 => ('0', 0),
+                1 => ('1', 0),
+                2 => ('2', 0),
+                3 => ('=', 1),
+                _ => ('-', 1),
+            };
+            digits.push(digit);
+            value = value.div_euclid(5) + carry;
+        }
+        write!(f, "{}", digits.into_iter().rev().collect::<String>())
+    }
+}
+
+impl From<i64> for Snafu {
+    fn from(value: i64) -> Self {
+        Snafu(value)
+    }
+}
+
+impl From<Snafu> for i64 {
+    fn from(value: Snafu) -> Self {
+        value.0
+    }
+}
+
+struct Day25;
+
+impl Solution for Day25 {
+    type Parsed<'a> = Vec<Snafu>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        input.lines()
+            .map(parse::<Snafu>)
+            .collect()
+    }
+
+    fn part1(numbers: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        let sum = numbers.iter()
+            .try_fold(0, |sum: i64, &n| sum.checked_add(n.0))
+            .ok_or_else(|| SolveError::new("Overflow"))?;
+        Ok(Snafu(sum).to_string().into())
+    }
+}
+
+const EXAMPLE_INPUT: &str = "\
+1=-0-2
+12111
+2=0=
+21
+2=01
+111
+20012
+112
+1=-1=
+1-12
+12
+1=
+122";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day25::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_25);
+    }
+
+    #[test]
+    fn test_conversion() {
+        let examples = [
+            (1, "1"), (2, "2"), (3, "1="), (4, "1-"), (5, "10"), (6, "11"), (7, "12"), (8, "2="), (9, "2-"),
+            (10, "20"), (15, "1=0"), (20, "1-0"), (2022, "1=11-2"), (12345, "1-0---0"), (314159265, "1121-1110-1=0"),
+        ];
+        for (decimal, snafu) in examples {
+            assert_eq!(snafu, Snafu::from(decimal).to_string());
+            assert_eq!(decimal, i64::from(snafu.parse::<Snafu>().unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert_eq!(Ok(Snafu(i64::MAX)), "1110--=-02=100==0-0=11=11212".parse());
+        assert_eq!(Err(SnafuError::OutOfRange), "1110--=-02=100==0-0=11=112120".parse::<Snafu>());
+        assert_eq!(Err(SnafuError::InvalidDigit('3')), "1=3".parse::<Snafu>());
+
+        let Err(error) = DAY_25.solver.solve("1110--=-02=100==0-0=11=11212\n1").unwrap().part1.unwrap().result else {
+            panic!("expected error");
+        };
+        assert_eq!("Overflow", error.message);
+    }
+}
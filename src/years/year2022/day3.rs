@@ -0,0 +1,85 @@
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, SecondPart, SolveError, Solution};
+
+pub const DAY_3: Day = Day {
+    solver: DaySolver::new::<Day3>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("157"), Some("70")),
+    ],
+};
+
+fn priority(x: u8) -> usize {
+    if x.is_ascii_lowercase() {
+        x as usize - 'a' as usize + 1
+    } else {
+        x as usize - 'A' as usize + 27
+    }
+}
+
+fn intersection(sets: &[&str]) -> Result<usize, SolveError> {
+    let mut seen = [0; 53];
+
+    sets.iter()
+        .enumerate()
+        .for_each(|(i, l)| l.bytes().for_each(|e| {
+            let p = priority(e);
+            if seen[p] == i {
+                seen[p] = i + 1;
+            }
+        }));
+
+    seen.into_iter().enumerate()
+        .find(|&(_, c)| c == sets.len())
+        .map(|(p, _)| p)
+        .ok_or_else(|| SolveError::at(sets[0], "No common item"))
+}
+
+struct Day3;
+
+impl Solution for Day3 {
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        let lines = input.lines().collect::<Vec<_>>();
+        if let Some(invalid) = lines.iter().find(|l| !l.bytes().all(|b| b.is_ascii_alphabetic())) {
+            return Err(SolveError::at(invalid, "Invalid item"));
+        }
+        Ok(lines)
+    }
+
+    fn part1(lines: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(lines.iter()
+            .map(|l| {
+                let (first, second) = l.split_at(l.len() / 2);
+                intersection(&[first, second])
+            })
+            .sum::<Result<usize, _>>()?.into())
+    }
+}
+
+impl SecondPart for Day3 {
+    fn part2(lines: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(lines.chunks_exact(3)
+            .map(intersection)
+            .sum::<Result<usize, _>>()?.into())
+    }
+}
+
+const EXAMPLE_INPUT: &str = "\
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day3::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_3);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, parse, SecondPart, SolveError, Solution, split_once};
+
+pub const DAY_4: Day = Day {
+    solver: DaySolver::new::<Day4>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("2"), Some("4")),
+    ],
+};
+
+struct Range {
+    from: i32,
+    to: i32,
+}
+
+impl Range {
+    fn fully_contains(&self, other: &Range) -> bool {
+        self.from <= other.from && self.to >= other.to
+    }
+
+    fn overlaps(&self, other: &Range) -> bool {
+        self.from <= other.to && self.to >= other.from
+    }
+}
+
+impl TryFrom<&str> for Range {
+    type Error = SolveError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let (from, to) = split_once(s.trim(), "-")?;
+        Ok(Self {
+            from: parse(from)?,
+            to: parse(to)?,
+        })
+    }
+}
+
+struct Pair {
+    first: Range,
+    second: Range,
+}
+
+impl TryFrom<&str> for Pair {
+    type Error = SolveError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let (first, second) = split_once(s.trim(), ",")?;
+        Ok(Self {
+            first: first.try_into()?,
+            second: second.try_into()?,
+        })
+    }
+}
+
+struct Day4;
+
+impl Solution for Day4 {
+    type Parsed<'a> = Vec<Pair>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        input
+            .lines()
+            .map(Pair::try_from)
+            .collect()
+    }
+
+    fn part1(pairs: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(pairs.iter()
+            .filter(|p| p.first.fully_contains(&p.second) || p.second.fully_contains(&p.first))
+            .count().into())
+    }
+}
+
+impl SecondPart for Day4 {
+    fn part2(pairs: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(pairs.iter()
+            .filter(|p| p.first.overlaps(&p.second))
+            .count().into())
+    }
+}
+
+const EXAMPLE_INPUT: &str = "\
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day4::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_4);
+    }
+
+    #[test]
+    fn test_overlaps() {
+        assert!(!Range { from: 2, to: 4 }.overlaps(&Range { from: 6, to: 8 }));
+        assert!(!Range { from: 6, to: 8 }.overlaps(&Range { from: 2, to: 4 }));
+        assert!(Range { from: 2, to: 4 }.overlaps(&Range { from: 3, to: 8 }));
+        assert!(Range { from: 3, to: 8 }.overlaps(&Range { from: 2, to: 4 }));
+        assert!(Range { from: 2, to: 8 }.overlaps(&Range { from: 4, to: 6 }));
+        assert!(Range { from: 4, to: 6 }.overlaps(&Range { from: 2, to: 8 }));
+    }
+}
\ No newline at end of file
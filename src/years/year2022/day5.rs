@@ -0,0 +1,127 @@
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, parse, SecondPart, SolveError, Solution, split_once, strip_prefix};
+
+pub const DAY_5: Day = Day {
+    solver: DaySolver::new::<Day5>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("CMZ"), Some("MCD")),
+    ],
+};
+
+type Stack = Vec<char>;
+
+struct Action {
+    quantity: usize,
+    from: usize,
+    to: usize,
+}
+
+impl TryFrom<&str> for Action {
+    type Error = SolveError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let (quantity, rest) = split_once(strip_prefix(s.trim(), "move ")?, " from ")?;
+        let (from, to) = split_once(rest, " to ")?;
+
+        let stack_index = |s: &str| parse::<usize>(s)?.checked_sub(1).ok_or_else(|| SolveError::at(s, "Invalid stack"));
+        Ok(Self {
+            quantity: parse(quantity)?,
+            from: stack_index(from)?,
+            to: stack_index(to)?,
+        })
+    }
+}
+
+fn build_stacks(cargo_drawing: &str) -> Result<Vec<Stack>, SolveError> {
+    let cargo_lines = cargo_drawing.lines()
+        .map(|l| l.chars().collect::<Vec<_>>())
+        .map(|l| l.chunks(4).enumerate()
+            .filter(|(_, c)| !c[0].is_ascii_whitespace() && c.len() > 1)
+            .map(|(i, c)| (i, c[1]))
+            .collect::<Vec<_>>())
+        .take_while(|l| !l.is_empty())
+        .collect::<Vec<_>>();
+
+    let stack_count = cargo_lines.iter()
+        .filter_map(|l| l.last().map(|(i, _)| i + 1))
+        .max()
+        .ok_or_else(|| SolveError::at(cargo_drawing, "No crates"))?;
+    let mut stacks = vec![Stack::new(); stack_count];
+    cargo_lines.into_iter().rev()
+        .for_each(|l| l.into_iter().for_each(|(i, c)| stacks[i].push(c)));
+    Ok(stacks)
+}
+
+struct Day5;
+
+impl Solution for Day5 {
+    type Parsed<'a> = (Vec<Stack>, Vec<Action>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        let (cargo_drawing, actions) = split_once(input, "\n\n")?;
+        let initial_stacks = build_stacks(cargo_drawing)?;
+        let actions = actions.lines()
+            .map(|l| Action::try_from(l).and_then(|a| validate_action(a, &initial_stacks, l)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok((initial_stacks, actions))
+    }
+
+    fn part1((initial_stacks, actions): &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        solve_part(initial_stacks.clone(), actions, |stacks, action| {
+            let start = stacks[action.from].len().checked_sub(action.quantity)?;
+            let values = stacks[action.from].drain(start..).rev().collect::<Vec<_>>();
+            stacks[action.to].extend_from_slice(&values);
+            Some(())
+        })
+    }
+}
+
+impl SecondPart for Day5 {
+    fn part2((initial_stacks, actions): &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        solve_part(initial_stacks.clone(), actions, |stacks, action| {
+            let start = stacks[action.from].len().checked_sub(action.quantity)?;
+            let values = stacks[action.from].drain(start..).collect::<Vec<_>>();
+            stacks[action.to].extend_from_slice(&values);
+            Some(())
+        })
+    }
+}
+
+fn validate_action(action: Action, stacks: &[Stack], line: &str) -> Result<Action, SolveError> {
+    if action.from >= stacks.len() || action.to >= stacks.len() {
+        return Err(SolveError::at(line, "Invalid stack"));
+    }
+    Ok(action)
+}
+
+fn solve_part(mut stacks: Vec<Stack>, actions: &[Action], execute_action: fn(&mut [Stack], &Action) -> Option<()>) -> Result<Answer, SolveError> {
+    for action in actions {
+        execute_action(&mut stacks, action).ok_or_else(|| SolveError::new("Moved more crates than a stack holds"))?;
+    }
+
+    Ok(stacks.into_iter()
+        .filter_map(|l| l.last().cloned())
+        .collect::<String>()
+        .into())
+}
+
+const EXAMPLE_INPUT: &str = "    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day5::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_5);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, SecondPart, SolveError, Solution};
+
+pub const DAY_6: Day = Day {
+    solver: DaySolver::new::<Day6>(),
+    examples: &[
+        Example::new("mjqjpqmgbljsphdztnvjfqwrcgsmlb", Some("7"), Some("19")),
+        Example::new("bvwbjplbgvbhsrlpgdmjqwftvncz", Some("5"), Some("23")),
+        Example::new("nppdvjthqldpwncqszvftbrmjlhg", Some("6"), Some("23")),
+        Example::new("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", Some("10"), Some("29")),
+        Example::new("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", Some("11"), Some("26")),
+    ],
+};
+
+type CharacterCount = [usize; char_to_index('z') + 1];
+
+const fn char_to_index(c: char) -> usize {
+    c as usize - 'a' as usize
+}
+
+fn no_duplicates(counts: &CharacterCount) -> bool {
+    counts.iter().cloned().all(|c| c < 2)
+}
+
+fn solver(characters: &[char], window_size: usize) -> Result<Answer, SolveError> {
+    if characters.len() < window_size {
+        return Err(SolveError::new("Input is shorter than the marker"));
+    }
+
+    let mut counts: CharacterCount = Default::default();
+    characters.iter()
+        .take(window_size).cloned()
+        .map(char_to_index)
+        .for_each(|i| counts[i] += 1);
+
+    if no_duplicates(&counts) {
+        return Ok(window_size.into());
+    }
+
+    for i in window_size..characters.len() {
+        counts[char_to_index(characters[i - window_size])] -= 1;
+        counts[char_to_index(characters[i])] += 1;
+
+        if no_duplicates(&counts) {
+            return Ok((i + 1).into());
+        }
+    }
+    Err(SolveError::new("No solution"))
+}
+
+struct Day6;
+
+impl Solution for Day6 {
+    type Parsed<'a> = Vec<char>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        let input = input.trim();
+        if let Some(invalid) = input.find(|c: char| !c.is_ascii_lowercase()) {
+            return Err(SolveError::at(&input[invalid..], "Invalid character"));
+        }
+        Ok(input.chars().collect())
+    }
+
+    fn part1(characters: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        solver(characters, 4)
+    }
+}
+
+impl SecondPart for Day6 {
+    fn part2(characters: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        solver(characters, 14)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day6::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_6);
+    }
+}
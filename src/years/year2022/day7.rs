@@ -0,0 +1,93 @@
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, parse, SecondPart, SolveError, Solution, split_once};
+
+pub const DAY_7: Day = Day {
+    solver: DaySolver::new::<Day7>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("95437"), Some("24933642")),
+    ],
+};
+
+struct Directories {
+    sizes: Vec<usize>,
+    total_used_space: usize,
+}
+
+struct Day7;
+
+impl Solution for Day7 {
+    type Parsed<'a> = Directories;
+
+    // Based on the observation that the input does an in-order tree-walk.
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        let mut directories = Vec::<usize>::new();
+        let mut current_size = vec![];
+        for line in input.lines().map(str::trim) {
+            if line == "$ cd .." {
+                directories.push(current_size.pop().ok_or_else(|| SolveError::at(line, "Left the root directory"))?);
+            } else if line == "$ ls" {
+                current_size.push(0);
+            } else if line.starts_with(|c: char| c.is_ascii_digit()) {
+                let (size, _) = split_once(line, " ")?;
+                let size = parse::<usize>(size)?;
+                current_size.iter_mut().for_each(|e| *e += size);
+            }
+        }
+
+        let total_used_space = *current_size.first().ok_or_else(|| SolveError::new("No directories listed"))?;
+        directories.append(&mut current_size);
+        Ok(Directories { sizes: directories, total_used_space })
+    }
+
+    fn part1(directories: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(directories.sizes.iter().cloned()
+            .filter(|&e| e <= 100000)
+            .sum::<usize>().into())
+    }
+}
+
+impl SecondPart for Day7 {
+    fn part2(directories: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        let unused_space = 70000000usize.checked_sub(directories.total_used_space).ok_or_else(|| SolveError::new("Disk is overfull"))?;
+        let to_be_freed = 30000000usize.saturating_sub(unused_space);
+        Ok(directories.sizes.iter().cloned()
+            .filter(|&e| e >= to_be_freed)
+            .min().ok_or_else(|| SolveError::new("No solution"))?.into())
+    }
+}
+
+const EXAMPLE_INPUT: &str = "\
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::assert_examples;
+    use crate::years::year2022::day7::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_7);
+    }
+}
\ No newline at end of file
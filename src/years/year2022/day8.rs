@@ -0,0 +1,181 @@
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, Rendering, SecondPart, SolveError, Solution};
+use crate::render::{Canvas, Color, Pixel, Render};
+
+pub const DAY_8: Day = Day {
+    solver: DaySolver::new::<Day8>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT, Some("21"), Some("8")),
+    ],
+};
+
+type Map = Vec<Vec<u8>>;
+
+struct Day8;
+
+impl Solution for Day8 {
+    type Parsed<'a> = Map;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        let map = input.lines()
+            .map(|l| match l.find(|c: char| !c.is_ascii_digit()) {
+                Some(i) => Err(SolveError::at(&l[i..], "Invalid tree height")),
+                None => Ok(l.bytes().map(|b| b - b'0').collect::<Vec<_>>()),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let width = map.first().map_or(0, Vec::len);
+        if width == 0 || map.iter().any(|r| r.len() != width) {
+            return Err(SolveError::new("Map is not a non-empty rectangle"));
+        }
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(visible_trees(map, map.len(), map[0].len()).into())
+    }
+
+    fn render(map: &Self::Parsed<'_>) -> Result<Rendering, SolveError> {
+        let visible = visibility(map, map.len(), map[0].len());
+        Ok(Some(Box::new(Forest { map: map.clone(), visible })))
+    }
+}
+
+impl SecondPart for Day8 {
+    fn part2(map: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(best_scenic_score(map, map.len(), map[0].len()).into())
+    }
+}
+
+// Taller trees are lighter, and trees hidden from outside the grid are dimmed.
+struct Forest {
+    map: Map,
+    visible: Vec<Vec<bool>>,
+}
+
+impl Render for Forest {
+    fn size(&self) -> (usize, usize) {
+        (self.map[0].len(), self.map.len())
+    }
+
+    fn render(&self, canvas: &mut dyn Canvas) {
+        for (y, (row, visible)) in self.map.iter().zip(&self.visible).enumerate() {
+            for (x, (&height, &visible)) in row.iter().zip(visible).enumerate() {
+                let color = Color(20, 60, 20).mix(Color(140, 230, 90), height as f64 / 9.0);
+                let color = if visible { color } else { color.mix(Color::BLACK, 0.6) };
+                canvas.draw(x, y, Pixel::new((b'0' + height) as char, color));
+            }
+        }
+    }
+}
+
+fn compute_horizontal_visibility<R: Iterator<Item=usize>>(map: &Map, seen: &mut [Vec<bool>], r: usize, mut cs: R) {
+    let first_c = cs.next().unwrap();
+    let mut tallest = map[r][first_c];
+    seen[r][first_c] = true;
+    for c in cs {
+        if map[r][c] > tallest {
+            tallest = map[r][c];
+            seen[r][c] = true;
+        }
+    }
+}
+
+fn compute_vertical_visibility<R: Iterator<Item=usize>>(map: &Map, seen: &mut [Vec<bool>], mut rs: R, c: usize) {
+    let first_r = rs.next().unwrap();
+    let mut tallest = map[first_r][c];
+    seen[first_r][c] = true;
+    for r in rs {
+        if map[r][c] > tallest {
+            tallest = map[r][c];
+            seen[r][c] = true;
+        }
+    }
+}
+
+fn visibility(map: &Map, height: usize, width: usize) -> Vec<Vec<bool>> {
+    let mut seen = vec![vec![false; width]; height];
+
+    for r in 0..height {
+        compute_horizontal_visibility(map, &mut seen, r, 0..width);
+        compute_horizontal_visibility(map, &mut seen, r, (0..width).rev());
+    }
+
+    for c in 0..width {
+        compute_vertical_visibility(map, &mut seen, 0..height, c);
+        compute_vertical_visibility(map, &mut seen, (0..height).rev(), c);
+    }
+
+    seen
+}
+
+fn visible_trees(map: &Map, height: usize, width: usize) -> usize {
+    visibility(map, height, width).into_iter()
+        .map(|l| l.into_iter().filter(|&b| b).count())
+        .sum::<usize>()
+}
+
+fn horizontal_count<R: Iterator<Item=usize>>(map: &Map, value: u8, r: usize, cs: R) -> usize {
+    let mut count = 0;
+    for dc in cs {
+        count += 1;
+        if map[r][dc] >= value { break; }
+    }
+    count
+}
+
+fn vertical_count<R: Iterator<Item=usize>>(map: &Map, value: u8, rs: R, c: usize) -> usize {
+    let mut count = 0;
+    for dr in rs {
+        count += 1;
+        if map[dr][c] >= value { break; }
+    }
+    count
+}
+
+fn best_scenic_score(map: &Map, height: usize, width: usize) -> usize {
+    (0..height).map(|r| (0..width).map(|c| {
+        let down_count = vertical_count(map, map[r][c], r + 1..height, c);
+        let up_count = vertical_count(map, map[r][c], (0..r).rev(), c);
+        let right_count = horizontal_count(map, map[r][c], r, c + 1..width);
+        let left_count = horizontal_count(map, map[r][c], r, (0..c).rev());
+        down_count * up_count * right_count * left_count
+    }).max().unwrap()).max().unwrap()
+}
+
+const EXAMPLE_INPUT: &str = "\
+30373
+25512
+65332
+33549
+35390";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::{assert_examples, Parameters};
+    use crate::render::Backend;
+    use crate::years::year2022::day8::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_8);
+    }
+
+    #[test]
+    fn test_render() {
+        let drawing = DAY_8.solver.render(EXAMPLE_INPUT, &Parameters::default()).unwrap().unwrap();
+        let text = String::from_utf8(Backend::Ascii.render(drawing.as_ref())).unwrap();
+        assert_eq!(format!("{}\n", EXAMPLE_INPUT), text);
+
+        let svg = String::from_utf8(Backend::Svg.render(drawing.as_ref())).unwrap();
+        let fill = |x: usize, y: usize| {
+            let rect = svg.lines().find(|l| l.starts_with(&format!("<rect x=\"{}\" y=\"{}\"", x, y))).unwrap();
+            let hex = &rect[rect.find('#').unwrap() + 1..][..6];
+            let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+            Color(channel(0), channel(2), channel(4))
+        };
+
+        // The hidden 3 in the middle is a dimmed version of the 3 visible from the right.
+        assert_eq!(fill(3, 2).mix(Color::BLACK, 0.6), fill(2, 2));
+        assert_ne!(fill(3, 2), fill(2, 2));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,205 @@
+use std::collections::HashSet;
+
+use crate::answer::Answer;
+use crate::day::{Day, DaySolver, Example, parse, Rendering, SecondPart, SolveError, Solution, split_once};
+use crate::render::{Canvas, Color, Pixel, Render};
+use crate::years::year2022::day9::Direction::*;
+
+pub const DAY_9: Day = Day {
+    solver: DaySolver::new::<Day9>(),
+    examples: &[
+        Example::new(EXAMPLE_INPUT_1, Some("13"), Some("1")),
+        Example::new(EXAMPLE_INPUT_2, None, Some("36")),
+    ],
+};
+
+#[derive(Copy, Clone)]
+enum Direction { Right, Left, Up, Down }
+
+impl Direction {
+    fn direction(self) -> (i32, i32) {
+        match self {
+            Right => (1, 0),
+            Left => (-1, 0),
+            Up => (0, 1),
+            Down => (0, -1),
+        }
+    }
+}
+
+impl TryFrom<&str> for Direction {
+    type Error = SolveError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "R" => Ok(Right),
+            "L" => Ok(Left),
+            "U" => Ok(Up),
+            "D" => Ok(Down),
+            _ => Err(SolveError::at(s, "Invalid direction")),
+        }
+    }
+}
+
+struct Action {
+    direction: Direction,
+    distance: i32,
+}
+
+impl TryFrom<&str> for Action {
+    type Error = SolveError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let (direction, distance) = split_once(s, " ")?;
+        Ok(Self {
+            direction: direction.try_into()?,
+            distance: parse(distance)?,
+        })
+    }
+}
+
+fn update_tail(head: (i32, i32), mut tail: (i32, i32)) -> (i32, i32) {
+    let horizontal_disjoint = (head.0 - tail.0).abs() > 1;
+    let vertical_disjoint = (head.1 - tail.1).abs() > 1;
+
+    if horizontal_disjoint && vertical_disjoint {
+        tail.0 += (head.0 - tail.0).signum();
+        tail.1 += (head.1 - tail.1).signum();
+    } else if horizontal_disjoint {
+        tail.0 += (head.0 - tail.0).signum();
+        tail.1 = head.1;
+    } else if vertical_disjoint {
+        tail.1 += (head.1 - tail.1).signum();
+        tail.0 = head.0;
+    }
+    tail
+}
+
+struct Day9;
+
+impl Solution for Day9 {
+    type Parsed<'a> = Vec<Action>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        input.lines().map(Action::try_from).collect()
+    }
+
+    fn part1(actions: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(tail_visits(actions, 2).len().into())
+    }
+
+    fn render(actions: &Self::Parsed<'_>) -> Result<Rendering, SolveError> {
+        Ok(Some(Box::new(Visits { short: tail_visits(actions, 2), long: tail_visits(actions, 10) })))
+    }
+}
+
+impl SecondPart for Day9 {
+    fn part2(actions: &Self::Parsed<'_>) -> Result<Answer, SolveError> {
+        Ok(tail_visits(actions, 10).len().into())
+    }
+}
+
+// The positions visited by the tails of both ropes, with up drawn at the top.
+struct Visits {
+    short: HashSet<(i32, i32)>,
+    long: HashSet<(i32, i32)>,
+}
+
+impl Visits {
+    // Both ropes start at the origin, so the bounds are never empty.
+    fn bounds(&self) -> ((i32, i32), (i32, i32)) {
+        let all = || self.short.iter().chain(&self.long);
+        let x = (all().map(|p| p.0).min().unwrap_or(0), all().map(|p| p.0).max().unwrap_or(0));
+        let y = (all().map(|p| p.1).min().unwrap_or(0), all().map(|p| p.1).max().unwrap_or(0));
+        (x, y)
+    }
+}
+
+impl Render for Visits {
+    fn size(&self) -> (usize, usize) {
+        let ((min_x, max_x), (min_y, max_y)) = self.bounds();
+        ((max_x - min_x) as usize + 1, (max_y - min_y) as usize + 1)
+    }
+
+    fn render(&self, canvas: &mut dyn Canvas) {
+        let ((min_x, max_x), (min_y, max_y)) = self.bounds();
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                let pixel = match (self.short.contains(&(x, y)), self.long.contains(&(x, y))) {
+                    _ if (x, y) == (0, 0) => Pixel::new('s', Color(230, 40, 40)),
+                    (true, true) => Pixel::new('#', Color::WHITE),
+                    (true, false) => Pixel::new('1', Color(240, 160, 40)),
+                    (false, true) => Pixel::new('9', Color(70, 140, 240)),
+                    (false, false) => Pixel::new('.', Color(20, 20, 30)),
+                };
+                canvas.draw((x - min_x) as usize, (max_y - y) as usize, pixel);
+            }
+        }
+    }
+}
+
+fn tail_visits(actions: &[Action], length: usize) -> HashSet<(i32, i32)> {
+    let mut snake = vec![(0, 0); length];
+
+    let mut tail_positions = HashSet::new();
+    tail_positions.insert((0, 0));
+
+    for action in actions {
+        let (dx, dy) = action.direction.direction();
+        for _ in 0..action.distance {
+            snake[0].0 += dx;
+            snake[0].1 += dy;
+
+            for i in 0..snake.len() - 1 {
+                snake[i + 1] = update_tail(snake[i], snake[i + 1]);
+            }
+
+            tail_positions.insert(snake[length - 1]);
+        }
+    }
+
+    tail_positions
+}
+
+const EXAMPLE_INPUT_1: &str = "\
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
+const EXAMPLE_INPUT_2: &str = "\
+R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+
+#[cfg(test)]
+mod tests {
+    use crate::day::{assert_examples, Parameters};
+    use crate::render::Backend;
+    use crate::years::year2022::day9::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(&DAY_9);
+    }
+
+    #[test]
+    fn test_render() {
+        let drawing = DAY_9.solver.render(EXAMPLE_INPUT_2, &Parameters::default()).unwrap().unwrap();
+        let text = String::from_utf8(Backend::Ascii.render(drawing.as_ref())).unwrap();
+        // Rows run from the top down, so the final move up ends in the first row.
+        assert_eq!(Some("1........................."), text.lines().next());
+        assert_eq!(Some("#........11111###11111111."), text.lines().nth(9));
+        assert_eq!(Some("1...9......s1111.....9...1"), text.lines().nth(14));
+        assert_eq!(Some(".11111111########11111111."), text.lines().last());
+    }
+}
\ No newline at end of file
@@ -14,11 +14,20 @@ mod day13;
 mod day14;
 mod day15;
 mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
 
-use crate::day::DaySolver;
+use crate::day::Day;
 
 
-pub const DAYS: [Option<DaySolver>; 25] = [
+pub const DAYS: [Option<Day>; 25] = [
     Some(day1::DAY_1),
     Some(day2::DAY_2),
     Some(day3::DAY_3),
@@ -35,13 +44,13 @@ pub const DAYS: [Option<DaySolver>; 25] = [
     Some(day14::DAY_14),
     Some(day15::DAY_15),
     Some(day16::DAY_16),
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
+    Some(day17::DAY_17),
+    Some(day18::DAY_18),
+    Some(day19::DAY_19),
+    Some(day20::DAY_20),
+    Some(day21::DAY_21),
+    Some(day22::DAY_22),
+    Some(day23::DAY_23),
+    Some(day24::DAY_24),
+    Some(day25::DAY_25),
 ];
\ No newline at end of file
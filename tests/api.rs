@@ -0,0 +1,64 @@
+use aoc2022_rs::{solve, solve_with, solve_year, Answers, Error};
+use aoc2022_rs::answer::Answer;
+use aoc2022_rs::day::{Location, Parameters};
+use aoc2022_rs::years;
+
+#[test]
+fn test_solve() {
+    let answers = solve(1, "1000\n2000\n\n3000\n\n4000\n").unwrap();
+
+    assert_eq!(Answers { part1: Answer::Integer(4000), part2: Some(Answer::Integer(10000)) }, answers);
+}
+
+#[test]
+fn test_solve_without_part2() {
+    let answers = solve(25, "1=\n").unwrap();
+
+    assert_eq!(Answer::Text("1=".to_owned()), answers.part1);
+    assert_eq!(None, answers.part2);
+}
+
+#[test]
+fn test_solve_with_parameters() {
+    let input = years::get(2022, 15).unwrap().examples[0].input;
+    let answers = solve_with(2022, 15, input, &Parameters::from_iter([("row", 10), ("window", 20)])).unwrap();
+
+    assert_eq!(Answers { part1: Answer::Integer(26), part2: Some(Answer::Integer(56000011)) }, answers);
+}
+
+#[test]
+fn test_no_solver() {
+    assert_eq!(Err(Error::NoSolver { year: 2022, day: 26 }), solve(26, ""));
+    assert_eq!(Err(Error::NoSolver { year: 2015, day: 1 }), solve_year(2015, 1, ""));
+}
+
+#[test]
+fn test_solve_error() {
+    let Err(Error::Solve(error)) = solve(1, "1000\n20x0\n") else {
+        panic!("expected error");
+    };
+
+    assert_eq!(Some(Location { line: 2, column: 1, text: "20x0".to_owned() }), error.location);
+}
+
+#[test]
+fn test_registry_examples() {
+    assert!(years::has_year(years::DEFAULT_YEAR));
+    for day in 1..=25 {
+        let day_solver = years::get(years::DEFAULT_YEAR, day).unwrap();
+        for example in day_solver.examples {
+            for check in day_solver.check_example(example).unwrap() {
+                assert!(check.passed(), "day {} part {}: expected {}, got {:?}", day, check.part, check.expected, check.actual);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_report() {
+    let report = years::get(2022, 6).unwrap().solver.solve("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap();
+
+    assert_eq!(Ok(Answer::Integer(7)), report.part1.unwrap().result);
+    assert_eq!(Ok(Answer::Integer(19)), report.part2.unwrap().result);
+    assert!(report.total_timing.is_some());
+}